@@ -0,0 +1,62 @@
+//! Compares validating a `&[bool]` chunk-wise, as `bool` does, to doing it
+//! one byte at a time, as the default `exhume_slice` does.
+//!
+//! Run with `cargo bench --bench bool_slice`.
+
+extern crate ignominie;
+
+use ignominie::{Error, Exhume, Heap, decode};
+use std::hint::black_box;
+use std::mem;
+use std::slice;
+use std::time::{Duration, Instant};
+
+/// A `bool` exhumed through the default `exhume_slice`.
+#[repr(transparent)]
+struct Naive(bool);
+
+impl<'input> Exhume<'input> for Naive {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        bool::exhume(&mut (*this).0, heap)
+    }
+}
+
+const LEN: usize = 1 << 20;
+const RUNS: u32 = 100;
+
+fn input() -> Vec<usize> {
+    let word = mem::size_of::<usize>();
+    // Every byte of `usize::MAX / 255` is 1.
+    let mut words = vec![usize::MAX / 255; 2 + LEN / word];
+    words[0] = 2 * word;
+    words[1] = LEN;
+    words
+}
+
+fn run<F>(name: &str, f: F)
+where
+    F: Fn(&mut [u8]) -> bool,
+{
+    let mut total = Duration::default();
+    for _ in 0..RUNS {
+        let mut words = input();
+        let bytes = unsafe {
+            slice::from_raw_parts_mut(
+                words.as_mut_ptr() as *mut u8,
+                words.len() * mem::size_of::<usize>(),
+            )
+        };
+        let start = Instant::now();
+        assert!(black_box(f(black_box(bytes))));
+        total += start.elapsed();
+    }
+    println!("{:>8}: {:?} per {} bools", name, total / RUNS, LEN);
+}
+
+fn main() {
+    run("chunked", |bytes| decode::<&[bool]>(bytes).is_ok());
+    run("naive", |bytes| decode::<&[Naive]>(bytes).is_ok());
+}
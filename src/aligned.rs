@@ -0,0 +1,84 @@
+use Exhume;
+use core::mem;
+use error::{self, Error};
+use heap::decode;
+
+/// `N` bytes aligned like `A`, so that a root of type `A` can be decoded
+/// from them without any chance of misalignment.
+///
+/// ```ignore
+/// let mut input = AlignedBytes::<Header, 64>::new(bytes);
+/// let header = decode_aligned(&mut input)?;
+/// ```
+#[repr(C)]
+pub struct AlignedBytes<A, const N: usize> {
+    align: [A; 0],
+    bytes: [u8; N],
+}
+
+impl<A, const N: usize> AlignedBytes<A, N> {
+    pub fn new(bytes: [u8; N]) -> Self {
+        AlignedBytes { align: [], bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+/// Decodes a `T` like `decode`, from bytes statically aligned for it.
+///
+/// Bytes of unknown alignment do not type-check:
+///
+/// ```compile_fail
+/// let mut bytes = [0u8; 8];
+/// ignominie::decode_aligned::<u64, 8>(&mut bytes);
+/// ```
+pub fn decode_aligned<'input, T, const N: usize>(
+    input: &'input mut AlignedBytes<T, N>,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    decode(&mut input.bytes)
+}
+
+/// An input whose alignment was checked once, so that many roots needing at
+/// most that alignment can be decoded from it.
+pub struct Buffer<'a> {
+    bytes: &'a mut [u8],
+    align: usize,
+}
+
+impl<'a> Buffer<'a> {
+    /// Wraps `bytes`, failing unless they are suitably aligned for `T`.
+    pub fn aligned_to<T>(bytes: &'a mut [u8]) -> Result<Self, Error> {
+        let align = mem::align_of::<T>();
+        if !(bytes.as_ptr() as usize).is_multiple_of(align) {
+            return Err(error::misaligned());
+        }
+        Ok(Buffer { bytes, align })
+    }
+
+    pub fn into_bytes(self) -> &'a mut [u8] {
+        self.bytes
+    }
+}
+
+/// Decodes a `T` like `decode`, failing if `T` needs more alignment than
+/// `input` was checked for.
+pub fn decode_buffer<'input, T>(
+    input: Buffer<'input>,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    if mem::align_of::<T>() > input.align {
+        return Err(error::misaligned());
+    }
+    decode(input.bytes)
+}
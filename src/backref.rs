@@ -0,0 +1,44 @@
+use Exhume;
+use core::mem;
+use core::ops::Deref;
+use error::{self, Error, PointerKind};
+use heap::Heap;
+
+/// A reference which may point backward, to a value reserved earlier in
+/// the traversal, e.g. an entry of a dictionary shared by several nodes.
+///
+/// Its pointee must have been exhumed as a `T` already, i.e. be the pointee
+/// of a `&T` or an element of a `&[T]` which is not an ancestor of the
+/// `BackRef`, and is not exhumed again. Keeping track of exhumed values
+/// takes memory, so only `decode_with_back_refs` does it, and `BackRef`
+/// fails to exhume anywhere else.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct BackRef<'input, T: 'input>(&'input T);
+
+impl<'input, T> Exhume<'input> for BackRef<'input, T>
+where
+    T: Exhume<'input>,
+{
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, usize>;
+        let offset = heap.native_word(this as *mut usize);
+        if heap.is_null(offset) {
+            return Err(error::null_pointer(PointerKind::Reference));
+        }
+        let ptr = heap.reserved::<T>(offset)?;
+        *this = BackRef(&*ptr);
+        Ok(())
+    }
+}
+
+impl<'input, T> Deref for BackRef<'input, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
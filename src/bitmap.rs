@@ -0,0 +1,94 @@
+use Exhume;
+use core::mem::MaybeUninit;
+use error::Error;
+use heap::Heap;
+
+/// A presence bitmap of `N` bytes, where bit `k` is bit `k % 8` of byte
+/// `k / 8` and tells whether the `k`-th optional field of a record is
+/// present.
+///
+/// ```ignore
+/// #[repr(C)]
+/// struct Record<'input> {
+///     presence: Bitmap<1>,
+///     name: Optional<&'input str>,
+///     age: Optional<u32>,
+/// }
+///
+/// impl<'input> Exhume<'input> for Record<'input> {
+///     unsafe fn exhume(
+///         this: *mut Self,
+///         heap: &mut Heap<'input>,
+///     ) -> Result<(), Error> {
+///         let presence = &(*this).presence;
+///         presence.exhume_field(0, &mut (*this).name, heap)?;
+///         presence.exhume_field(1, &mut (*this).age, heap)
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Bitmap<const N: usize>([u8; N]);
+
+impl<const N: usize> Bitmap<N> {
+    /// Returns whether bit `k` is set, bits past the end being unset.
+    pub fn contains(&self, k: usize) -> bool {
+        self.0.get(k / 8).is_some_and(|byte| byte & 1 << (k % 8) != 0)
+    }
+
+    /// Returns the `k`-th optional field, if this bitmap says it is present.
+    ///
+    /// # Safety
+    ///
+    /// `field` must be the `k`-th optional field of the record this bitmap
+    /// was decoded with, i.e. the one `exhume_field` was called on with `k`,
+    /// as the bitmap cannot tell whether any other was exhumed.
+    pub unsafe fn get<'a, T>(
+        &self,
+        k: usize,
+        field: &'a Optional<T>,
+    ) -> Option<&'a T> {
+        if self.contains(k) {
+            Some(field.0.assume_init_ref())
+        } else {
+            None
+        }
+    }
+
+    /// Exhumes the `k`-th optional field if this bitmap says it is present,
+    /// leaving its bytes untouched otherwise.
+    ///
+    /// # Safety
+    ///
+    /// Same as `Exhume::exhume`, for `this`.
+    pub unsafe fn exhume_field<'input, T>(
+        &self,
+        k: usize,
+        this: *mut Optional<T>,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error>
+    where
+        T: Exhume<'input>,
+    {
+        if self.contains(k) {
+            T::exhume(this as *mut T, heap)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'input, const N: usize> Exhume<'input> for Bitmap<N> {
+    const IS_RELOCATION_FREE: bool = true;
+
+    unsafe fn exhume(
+        _this: *mut Self,
+        _heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// A field whose presence is recorded in a `Bitmap`, occupying the bytes of
+/// a `T` either way. Its contents can only be reached through the bitmap.
+#[repr(transparent)]
+pub struct Optional<T>(MaybeUninit<T>);
@@ -0,0 +1,109 @@
+use Exhume;
+use core::mem;
+use error::Error;
+use heap::Heap;
+
+/// A set of indices stored as a `&[u64]`, where index `k` is bit `k % 64`
+/// of word `k / 64`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct BitSet<'input>(&'input [u64]);
+
+impl<'input> BitSet<'input> {
+    /// Returns whether `index` is in the set, indices past the end of the
+    /// words not being in it.
+    pub fn contains(&self, index: usize) -> bool {
+        self.0
+            .get(index / 64)
+            .is_some_and(|word| word & 1 << (index % 64) != 0)
+    }
+
+    /// Returns the indices in the set, in increasing order.
+    pub fn iter_ones(&self) -> Ones<'input> {
+        Ones { words: self.0, end: 0, word: 0 }
+    }
+
+    pub fn as_words(&self) -> &'input [u64] {
+        self.0
+    }
+}
+
+impl<'input> Exhume<'input> for BitSet<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, &[u64]>;
+        <&[u64]>::exhume(this as *mut &[u64], heap)
+    }
+}
+
+/// `len` booleans packed as the bits of a `&[u8]`, where boolean `k` is
+/// bit `k % 8` of byte `k / 8`, followed by `len` as a `usize`.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PackedBools<'input> {
+    bytes: &'input [u8],
+    len: usize,
+}
+
+impl<'input> PackedBools<'input> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the boolean at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `len()`.
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "index out of bounds");
+        self.bytes[index / 8] & 1 << (index % 8) != 0
+    }
+}
+
+impl<'input> Exhume<'input> for PackedBools<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        <&[u8]>::exhume(&mut (*this).bytes, heap)?;
+        let len = heap.native(&mut (*this).len as *mut usize);
+        let bytes = (*this).bytes;
+        match bytes.len().checked_mul(8) {
+            Some(bits) if len <= bits => Ok(()),
+            _ => heap.invalid(),
+        }
+    }
+}
+
+/// The indices in a `BitSet`, see `BitSet::iter_ones`.
+#[derive(Clone, Debug)]
+pub struct Ones<'input> {
+    words: &'input [u64],
+    /// The index right past the highest bit of `word`.
+    end: usize,
+    /// The bits of the current word not returned yet.
+    word: u64,
+}
+
+impl<'input> Iterator for Ones<'input> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            let (&word, words) = self.words.split_first()?;
+            self.words = words;
+            self.word = word;
+            self.end += 64;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.end - 64 + bit)
+    }
+}
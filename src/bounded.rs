@@ -0,0 +1,39 @@
+use Exhume;
+use core::mem;
+use core::ops::Deref;
+use error::{self, Error};
+use heap::Heap;
+
+/// A `&[T]` rejected with `ErrorKind::LimitExceeded` when longer than
+/// `MAX`, before any of its elements are exhumed.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct BoundedSlice<'input, T: 'input, const MAX: usize>(&'input [T]);
+
+impl<'input, T, const MAX: usize> Exhume<'input>
+    for BoundedSlice<'input, T, MAX>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, &[T]>;
+        let raw = *(this as *const *const [T]);
+        if heap.to_native_word(raw.len()) > MAX {
+            return Err(error::limit_exceeded());
+        }
+        <&[T]>::exhume(this as *mut &[T], heap)
+    }
+}
+
+impl<'input, T, const MAX: usize> Deref for BoundedSlice<'input, T, MAX> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
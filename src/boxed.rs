@@ -0,0 +1,47 @@
+use Exhume;
+use core::mem;
+use core::ops::Deref;
+use error::Error;
+use heap::Heap;
+
+/// A `&[T]` meant to be copied out of the input once decoded, for callers
+/// who want to own one field without owning the whole value.
+///
+/// It is exhumed like `&[T]`, so its elements are relocated before they
+/// can be copied. The copy only stops borrowing the input if `T` does not,
+/// i.e. if it does not hold references itself.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct BoxedSlice<'input, T: 'input>(&'input [T]);
+
+impl<'input, T> BoxedSlice<'input, T> {
+    /// Copies the elements into a new `Box<[T]>`, with a single bulk copy
+    /// when `T` is `Copy`.
+    pub fn to_boxed(&self) -> Box<[T]>
+    where
+        T: Clone,
+    {
+        self.0.into()
+    }
+}
+
+impl<'input, T> Exhume<'input> for BoxedSlice<'input, T>
+where
+    T: Exhume<'input>,
+{
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, &[T]>;
+        <&[T]>::exhume(this as *mut &[T], heap)
+    }
+}
+
+impl<'input, T> Deref for BoxedSlice<'input, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
@@ -0,0 +1,159 @@
+use Pair;
+use core::hash::Hasher;
+
+/// Feeds the logical content of a decoded value to a `Hasher`, following
+/// references and skipping padding, so that equal values hash equally
+/// whatever the layout of the buffers they were decoded from.
+///
+/// Integers are fed as little-endian bytes, `usize` and `isize` as 64-bit
+/// ones, and slices are prefixed by their length, so the result does not
+/// depend on the host either.
+pub trait CanonicalHash {
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher;
+}
+
+pub fn canonical_hash<T, H>(value: &T, state: &mut H)
+where
+    T: CanonicalHash + ?Sized,
+    H: Hasher,
+{
+    value.canonical_hash(state)
+}
+
+macro_rules! int_impl {
+    ($($ty:ty as $wide:ty,)+) => {
+        $(impl CanonicalHash for $ty {
+            fn canonical_hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
+            {
+                state.write(&(*self as $wide).to_le_bytes());
+            }
+        })+
+    };
+}
+
+int_impl! {
+    u8 as u8,
+    u16 as u16,
+    u32 as u32,
+    u64 as u64,
+    usize as u64,
+    i8 as i8,
+    i16 as i16,
+    i32 as i32,
+    i64 as i64,
+    isize as i64,
+    bool as u8,
+    char as u32,
+}
+
+impl CanonicalHash for f32 {
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.to_bits().canonical_hash(state)
+    }
+}
+
+impl CanonicalHash for f64 {
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.to_bits().canonical_hash(state)
+    }
+}
+
+impl CanonicalHash for () {
+    fn canonical_hash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+impl CanonicalHash for str {
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_bytes().canonical_hash(state)
+    }
+}
+
+impl<T> CanonicalHash for [T]
+where
+    T: CanonicalHash,
+{
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.len().canonical_hash(state);
+        for element in self {
+            element.canonical_hash(state);
+        }
+    }
+}
+
+impl<T, const N: usize> CanonicalHash for [T; N]
+where
+    T: CanonicalHash,
+{
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        for element in self {
+            element.canonical_hash(state);
+        }
+    }
+}
+
+impl<T> CanonicalHash for &T
+where
+    T: CanonicalHash + ?Sized,
+{
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).canonical_hash(state)
+    }
+}
+
+impl<T> CanonicalHash for Option<T>
+where
+    T: CanonicalHash,
+{
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {
+            None => state.write_u8(0),
+            Some(ref value) => {
+                state.write_u8(1);
+                value.canonical_hash(state);
+            },
+        }
+    }
+}
+
+impl<A, B> CanonicalHash for Pair<A, B>
+where
+    A: CanonicalHash,
+    B: CanonicalHash,
+{
+    fn canonical_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.canonical_hash(state);
+        self.1.canonical_hash(state);
+    }
+}
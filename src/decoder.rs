@@ -0,0 +1,65 @@
+use Exhume;
+use core::marker::PhantomData;
+use core::slice;
+use error::{Error, ErrorKind};
+use heap::decode;
+
+/// Decodes a `T` from an input received in pieces.
+///
+/// Exhuming writes into the input, so every attempt is made on a copy of
+/// the bytes received so far, aligned to 8 bytes, leaving them untouched
+/// for the next one. An attempt failing with `ErrorKind::OutOfBounds`
+/// means more bytes are needed; any other error means the input is corrupt,
+/// `ErrorKind::Overflow` included, as no number of bytes would do.
+///
+/// Each `feed` thus costs as much as decoding everything received so far,
+/// so feeding `n` bytes in pieces of a few bytes costs `O(n²)`: buffer them
+/// until a sizeable piece arrived where that matters.
+///
+/// As with `SharedDecoded`, the root type must be exhumable for any
+/// lifetime.
+pub struct Decoder<T> {
+    received: Vec<u8>,
+    scratch: Vec<u64>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Decoder<T>
+where
+    T: for<'input> Exhume<'input>,
+{
+    pub fn new() -> Self {
+        Decoder {
+            received: Vec::new(),
+            scratch: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Appends `more` to the bytes received so far, and tries to decode
+    /// them, returning `Ok(None)` if they are not enough yet.
+    pub fn feed(&mut self, more: &[u8]) -> Result<Option<&T>, Error> {
+        self.received.extend_from_slice(more);
+        let len = self.received.len();
+        self.scratch.clear();
+        self.scratch.resize(len.div_ceil(8), 0);
+        let bytes = unsafe {
+            slice::from_raw_parts_mut(self.scratch.as_mut_ptr() as *mut u8, len)
+        };
+        bytes.copy_from_slice(&self.received);
+        match decode::<T>(bytes) {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if error.kind() == ErrorKind::OutOfBounds => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl<T> Default for Decoder<T>
+where
+    T: for<'input> Exhume<'input>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,79 @@
+use Exhume;
+use core::mem;
+use core::ops::Deref;
+use core::slice;
+use error::Error;
+use heap::Heap;
+
+/// A reference stored as the distance from the end of the previously
+/// reserved value to its pointee, rather than as an offset from the start
+/// of the input. Exhuming replaces it with a regular `&T`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct DeltaPtr<'input, T: 'input>(&'input T);
+
+impl<'input, T> Exhume<'input> for DeltaPtr<'input, T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, usize>;
+        let delta = heap.native_word(this as *mut usize);
+        let ptr = heap.reserve_delta::<T>(delta, 1)?;
+        heap.descend(|heap| T::exhume(ptr, heap))?;
+        heap.exhumed(ptr, 1);
+        *this = DeltaPtr(&*ptr);
+        Ok(())
+    }
+}
+
+impl<'input, T> Deref for DeltaPtr<'input, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+/// The `&[T]` counterpart of `DeltaPtr`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct DeltaSlice<'input, T: 'input>(&'input [T]);
+
+impl<'input, T> Exhume<'input> for DeltaSlice<'input, T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, [usize; 2]>;
+        heap.native_word(this as *mut usize);
+        heap.native_word((this as *mut usize).add(1));
+        // A delta of zero is a null data pointer, which a `&[T]` cannot be.
+        let stored = *(this as *const *const [T]);
+        let delta = stored as *const T as usize;
+        let len = stored.len();
+        let ptr = heap.reserve_delta::<T>(delta, len)?;
+        heap.descend(|heap| T::exhume_slice(ptr, len, heap))?;
+        heap.exhumed(ptr, len);
+        *this = DeltaSlice(slice::from_raw_parts(ptr, len));
+        Ok(())
+    }
+}
+
+impl<'input, T> Deref for DeltaSlice<'input, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
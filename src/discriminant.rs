@@ -0,0 +1,47 @@
+use Exhume;
+use core::mem::{self, Discriminant};
+use error::Error;
+use heap::Heap;
+
+/// The tag of a fieldless enum, laid out like the enum itself, i.e. as the
+/// integer of its `#[repr]`, and compared by discriminant.
+///
+/// `Exhume` is not implemented for `Discriminant<E>`, whose layout is not
+/// specified. This is what to store instead, with `E` implementing
+/// `Exhume` by rejecting unknown tags, as the impls for `Ordering` and the
+/// like do.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DiscriminantTag<E>(E);
+
+impl<E> DiscriminantTag<E> {
+    pub fn get(&self) -> &E {
+        &self.0
+    }
+
+    pub fn discriminant(&self) -> Discriminant<E> {
+        mem::discriminant(&self.0)
+    }
+}
+
+impl<E> PartialEq for DiscriminantTag<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.discriminant() == other.discriminant()
+    }
+}
+
+impl<E> Eq for DiscriminantTag<E> {}
+
+impl<'input, E> Exhume<'input> for DiscriminantTag<E>
+where
+    E: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = E::IS_RELOCATION_FREE;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        E::exhume(this as *mut E, heap)
+    }
+}
@@ -0,0 +1,119 @@
+use Exhume;
+use core::any::TypeId;
+use core::marker::PhantomData;
+use error::{self, Error, PointerKind};
+use heap::Heap;
+use type_id;
+
+/// Maps the tags of `TaggedRef`s to the types of their pointees.
+///
+/// ```ignore
+/// enum Shapes {}
+///
+/// unsafe impl<'input> Dispatch<'input> for Shapes {
+///     fn dispatch(tag: u32) -> Option<ExhumeFn<'input>> {
+///         match tag {
+///             0 => Some(ExhumeFn::of::<Circle>()),
+///             1 => Some(ExhumeFn::of::<Polygon<'input>>()),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// `dispatch` must be deterministic, always returning the same type for the
+/// same tag, as `TaggedRef::get` asks it again for the type its pointee was
+/// exhumed as.
+pub unsafe trait Dispatch<'input> {
+    /// Returns how to exhume the pointee of a `TaggedRef` with `tag`, or
+    /// `None` if the tag is unknown.
+    fn dispatch(tag: u32) -> Option<ExhumeFn<'input>>;
+}
+
+/// How to exhume a value of some type behind an offset, see `Dispatch`.
+#[derive(Clone, Copy)]
+pub struct ExhumeFn<'input> {
+    exhume: unsafe fn(usize, &mut Heap<'input>) -> Result<*const u8, Error>,
+    type_id: TypeId,
+}
+
+impl<'input> ExhumeFn<'input> {
+    pub fn of<T>() -> Self
+    where
+        T: Exhume<'input>,
+    {
+        ExhumeFn {
+            exhume: exhume_at::<T>,
+            type_id: type_id::of::<T>(),
+        }
+    }
+}
+
+unsafe fn exhume_at<'input, T>(
+    offset: usize,
+    heap: &mut Heap<'input>,
+) -> Result<*const u8, Error>
+where
+    T: Exhume<'input>,
+{
+    let ptr = heap.reserve::<T>(offset, 1)?;
+    heap.descend(|heap| T::exhume(ptr, heap))?;
+    heap.exhumed(ptr, 1);
+    Ok(ptr as *const u8)
+}
+
+/// A reference whose pointee type is selected by a `u32` tag stored before
+/// it, through `D`. Unknown tags are invalid.
+#[repr(C)]
+pub struct TaggedRef<'input, D> {
+    tag: u32,
+    ptr: *const u8,
+    marker: PhantomData<(&'input (), D)>,
+}
+
+impl<'input, D> TaggedRef<'input, D>
+where
+    D: Dispatch<'input>,
+{
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    /// Returns the pointee if `D` maps the tag to `T`.
+    pub fn get<T>(&self) -> Option<&'input T>
+    where
+        T: Exhume<'input>,
+    {
+        let exhume_fn = D::dispatch(self.tag)?;
+        if exhume_fn.type_id != type_id::of::<T>() {
+            return None;
+        }
+        Some(unsafe { &*(self.ptr as *const T) })
+    }
+}
+
+impl<'input, D> Exhume<'input> for TaggedRef<'input, D>
+where
+    D: Dispatch<'input>,
+{
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let tag = heap.native(&mut (*this).tag as *mut u32);
+        let ptr = &mut (*this).ptr as *mut *const u8;
+        let offset = heap.native_word(ptr as *mut usize);
+        if heap.is_null(offset) {
+            return Err(error::null_pointer(PointerKind::Reference));
+        }
+        match D::dispatch(tag) {
+            Some(exhume_fn) => {
+                (*this).ptr = (exhume_fn.exhume)(offset, heap)?;
+                Ok(())
+            },
+            None => heap.invalid(),
+        }
+    }
+}
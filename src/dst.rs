@@ -0,0 +1,128 @@
+use Exhume;
+use core::mem;
+use error::{self, Error, PointerKind};
+use heap::Heap;
+
+/// A `#[repr(C)]` dynamically-sized type made of a sized header followed
+/// by a slice of elements, whose length is stored in the header.
+///
+/// References to such types are exhumed through `unsized_exhume!`, which
+/// ignores the stored slice length and uses the one from the header.
+///
+/// ```ignore
+/// #[repr(C)]
+/// struct Header {
+///     len: u32,
+///     tag: bool,
+/// }
+///
+/// #[repr(C)]
+/// struct Entries {
+///     header: Header,
+///     tail: [u64],
+/// }
+///
+/// unsafe impl<'input> ExhumeUnsized<'input> for Entries {
+///     type Header = Header;
+///     type Element = u64;
+///
+///     unsafe fn tail_len(header: *const Header, heap: &Heap) -> usize {
+///         heap.read_native(&(*header).len) as usize
+///     }
+///
+///     fn from_raw_parts(data: *mut u8, len: usize) -> *mut Self {
+///         ptr::slice_from_raw_parts_mut(data, len) as *mut Self
+///     }
+/// }
+///
+/// unsized_exhume!(Entries);
+/// ```
+///
+/// # Safety
+///
+/// `Self` must be `#[repr(C)]`, with a `Header` as its first field and a
+/// `[Element]` as its last and only other one.
+pub unsafe trait ExhumeUnsized<'input> {
+    type Header: Exhume<'input>;
+    type Element: Exhume<'input>;
+
+    /// Reads the length of the tail from a header which was not exhumed yet,
+    /// through `Heap::read_native` as it is still in the byte order of the
+    /// input.
+    ///
+    /// # Safety
+    ///
+    /// `header` points to the initialised bytes of a `Header`, and only the
+    /// fields which are valid for any bytes may be read.
+    unsafe fn tail_len(
+        header: *const Self::Header,
+        heap: &Heap<'input>,
+    ) -> usize;
+
+    /// Builds a pointer to `Self` with `len` elements in its tail.
+    fn from_raw_parts(data: *mut u8, len: usize) -> *mut Self;
+}
+
+/// Implements `Exhume` for references to types implementing
+/// `ExhumeUnsized`.
+#[macro_export]
+macro_rules! unsized_exhume {
+    ($($ty:ty),+) => {
+        unsized_exhume!($($ty,)+);
+    };
+    ($($ty:ty,)+) => {
+        $(impl<'input> $crate::Exhume<'input> for &'input $ty {
+            const IS_RELOCATION_FREE: bool = false;
+
+            unsafe fn exhume(
+                this: *mut Self,
+                heap: &mut $crate::Heap<'input>,
+            ) -> $crate::__Result<(), $crate::Error> {
+                $crate::__exhume_unsized(this, heap)
+            }
+        })+
+    };
+}
+
+#[doc(hidden)]
+pub unsafe fn exhume_unsized<'input, U>(
+    this: *mut &'input U,
+    heap: &mut Heap<'input>,
+) -> Result<(), Error>
+where
+    U: ExhumeUnsized<'input> + ?Sized,
+{
+    let offset = heap.native_word(this as *mut usize);
+    if heap.is_null(offset) {
+        return Err(error::null_pointer(PointerKind::Reference));
+    }
+    let header = heap.reserve::<U::Header>(offset, 1)?;
+    if !(header as usize).is_multiple_of(mem::align_of::<U::Element>()) {
+        return Err(error::basic());
+    }
+    let len = U::tail_len(header, heap);
+    let header_size = mem::size_of::<U::Header>()
+        .next_multiple_of(mem::align_of::<U::Element>());
+    let tail = heap.reserve_relative::<U::Element>(
+        header as *const u8,
+        header_size as isize,
+        len,
+    )?;
+    // The reference also spans the trailing padding of `U`, which must thus
+    // lie within the input and not overlap the values reserved after it.
+    let align = mem::align_of::<U::Header>().max(mem::align_of::<U::Element>());
+    let size = header_size + len * mem::size_of::<U::Element>();
+    let padding = size
+        .checked_next_multiple_of(align)
+        .ok_or(error::overflow())? -
+        size;
+    heap.reserve_relative::<u8>(header as *const u8, size as isize, padding)?;
+    heap.descend(|heap| {
+        U::Header::exhume(header, heap)?;
+        U::Element::exhume_slice(tail, len, heap)
+    })?;
+    heap.exhumed(header, 1);
+    heap.exhumed(tail, len);
+    *this = &*U::from_raw_parts(header as *mut u8, len);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use Exhume;
+use core::ptr;
+use error::Error;
+use heap::Heap;
+
+/// An integer stored in big-endian byte order, converted in place to the
+/// byte order of the host when exhumed, whatever the input's is.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Be<T>(T);
+
+/// The little-endian counterpart of `Be`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Le<T>(T);
+
+impl<T> Be<T>
+where
+    T: Copy,
+{
+    pub fn get(&self) -> T {
+        self.0
+    }
+}
+
+impl<T> Le<T>
+where
+    T: Copy,
+{
+    pub fn get(&self) -> T {
+        self.0
+    }
+}
+
+/// A byte order, for `decode_header_endian`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// Returns the byte order of the host.
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+
+    pub fn is_native(self) -> bool {
+        self == Endianness::native()
+    }
+}
+
+macro_rules! endian_impl {
+    ($($ty:ty,)+) => {
+        $(impl<'input> Exhume<'input> for Be<$ty> {
+            const IS_RELOCATION_FREE: bool = true;
+
+            unsafe fn exhume(
+                this: *mut Self,
+                _heap: &mut Heap<'input>,
+            ) -> Result<(), Error> {
+                let ptr = this as *mut $ty;
+                ptr::write_unaligned(
+                    ptr,
+                    <$ty>::from_be(ptr::read_unaligned(ptr)),
+                );
+                Ok(())
+            }
+        }
+
+        impl<'input> Exhume<'input> for Le<$ty> {
+            const IS_RELOCATION_FREE: bool = true;
+
+            unsafe fn exhume(
+                this: *mut Self,
+                _heap: &mut Heap<'input>,
+            ) -> Result<(), Error> {
+                let ptr = this as *mut $ty;
+                ptr::write_unaligned(
+                    ptr,
+                    <$ty>::from_le(ptr::read_unaligned(ptr)),
+                );
+                Ok(())
+            }
+        })+
+    };
+}
+
+endian_impl!(u16, u32, u64, usize, i16, i32, i64, isize,);
@@ -1,7 +1,141 @@
+#[cfg(feature = "std")]
+use std::io;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Error {
-    _inner: ()
+    kind: ErrorKind,
+    index: Option<usize>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Error { kind, index: None }
+    }
+
+    /// Creates an error for input not representing a valid value, as most
+    /// `Exhume` impls report.
+    pub fn invalid() -> Self {
+        Error::new(ErrorKind::Invalid)
+    }
+
+    /// Creates an error with an application-defined code, for `Exhume` impls
+    /// to report failures that none of the other kinds describe.
+    pub fn custom(code: u32) -> Self {
+        Error::new(ErrorKind::Custom(code))
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the index of the slice or array element the error is in, if
+    /// any. In nested slices, this is the index in the innermost one.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    pub(crate) fn at_index(self, index: usize) -> Self {
+        Error { index: self.index.or(Some(index)), ..self }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input does not represent a valid value.
+    Invalid,
+    /// A pointer that must not be null was null.
+    NullPointer(PointerKind),
+    /// A C string has a nul byte elsewhere than at its end.
+    Nul(NulKind),
+    /// A value would extend past the end of the input.
+    OutOfBounds,
+    /// An offset or length is so large that computing where a value lies
+    /// overflows, so no input can hold it.
+    Overflow,
+    /// A value would overlap one which was already decoded.
+    Overlap,
+    /// The input is not aligned enough for what is decoded from it, see
+    /// `Buffer`.
+    Misaligned,
+    /// Decoding would follow more nested pointers than it was allowed to.
+    DepthExceeded,
+    /// Decoding would take more work than it was allowed to.
+    LimitExceeded,
+    /// Bytes were left over after decoding.
+    TrailingBytes,
+    /// The input was written for another type, see `decode_typed`.
+    SchemaMismatch,
+    /// An application-defined error, see `Error::custom`.
+    Custom(u32),
+    /// Reading the input failed, e.g. with `UnexpectedEof` when a frame
+    /// read by `decode_frame` was truncated.
+    #[cfg(feature = "std")]
+    Io(io::ErrorKind),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PointerKind {
+    /// The pointer of a `&T`.
+    Reference,
+    /// The data pointer of a `&[T]`.
+    Slice,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NulKind {
+    /// A nul byte comes before the last byte.
+    Interior,
+    /// The last byte is not a nul byte, or there is no byte at all.
+    Missing,
 }
 
 pub fn basic() -> Error {
-    Error { _inner: () }
+    Error::new(ErrorKind::Invalid)
+}
+
+pub fn out_of_bounds() -> Error {
+    Error::new(ErrorKind::OutOfBounds)
+}
+
+pub fn overflow() -> Error {
+    Error::new(ErrorKind::Overflow)
+}
+
+pub fn limit_exceeded() -> Error {
+    Error::new(ErrorKind::LimitExceeded)
+}
+
+pub fn depth_exceeded() -> Error {
+    Error::new(ErrorKind::DepthExceeded)
+}
+
+pub fn overlap() -> Error {
+    Error::new(ErrorKind::Overlap)
+}
+
+pub fn misaligned() -> Error {
+    Error::new(ErrorKind::Misaligned)
+}
+
+pub fn trailing_bytes() -> Error {
+    Error::new(ErrorKind::TrailingBytes)
+}
+
+pub fn schema_mismatch() -> Error {
+    Error::new(ErrorKind::SchemaMismatch)
+}
+
+pub fn null_pointer(pointer: PointerKind) -> Error {
+    Error::new(ErrorKind::NullPointer(pointer))
+}
+
+#[cfg(feature = "std")]
+pub fn nul(nul: NulKind) -> Error {
+    Error::new(ErrorKind::Nul(nul))
+}
+
+#[cfg(feature = "std")]
+pub fn io(error: io::Error) -> Error {
+    Error::new(ErrorKind::Io(error.kind()))
 }
@@ -0,0 +1,58 @@
+use Heap;
+use core::mem;
+use core::ops::{BitAnd, Not};
+use error::Error;
+use heap::SwapBytes;
+
+/// Implements `Exhume` for a `#[repr(transparent)]` bit-flag type over the
+/// given integer type, rejecting any value with bits set outside of the
+/// given mask.
+///
+/// ```ignore
+/// #[repr(transparent)]
+/// struct Flags(u32);
+///
+/// flags_exhume!(Flags: u32 => 0b1011);
+/// ```
+#[macro_export]
+macro_rules! flags_exhume {
+    ($($ty:ty: $bits:ty => $mask:expr),+) => {
+        flags_exhume!($($ty: $bits => $mask,)+);
+    };
+    ($($ty:ty: $bits:ty => $mask:expr,)+) => {
+        $(impl<'input> $crate::Exhume<'input> for $ty {
+            const IS_RELOCATION_FREE: bool = true;
+
+            unsafe fn exhume(
+                this: *mut Self,
+                heap: &mut $crate::Heap<'input>,
+            ) -> $crate::__Result<(), $crate::Error> {
+                let mask: $bits = $mask;
+                $crate::__exhume_flags(this, heap, mask)
+            }
+        })+
+    };
+}
+
+#[doc(hidden)]
+pub unsafe fn exhume_flags<'input, T, M>(
+    this: *mut T,
+    heap: &mut Heap<'input>,
+    mask: M,
+) -> Result<(), Error>
+where
+    M: BitAnd<Output = M> + Default + Not<Output = M> + PartialEq + SwapBytes,
+{
+    const {
+        assert!(
+            mem::size_of::<T>() == mem::size_of::<M>(),
+            "flags and their integer type differ in size",
+        );
+    }
+    let bits = heap.native(this as *mut M);
+    if bits & !mask == M::default() {
+        Ok(())
+    } else {
+        heap.invalid()
+    }
+}
@@ -1,26 +1,509 @@
-use Exhume;
+use {Exhume, Validate};
+#[cfg(feature = "std")]
+use core::any::TypeId;
 use core::marker::PhantomData;
-use core::mem;
+use core::mem::{self, MaybeUninit};
+use core::iter::FusedIterator;
+use core::ops::Range;
 use core::ptr;
 use core::slice;
-use error::{self, Error};
+use endian::Endianness;
+use error::{self, Error, PointerKind};
+#[cfg(feature = "std")]
+use type_id;
 
+/// Decodes a `T` from the start of `input`, exhuming it in place.
+///
+/// Values are exhumed depth-first, following pointers in the order their
+/// impls do, usually field order, and each must lie past all the values
+/// reserved before it. Any tree can thus be laid out, including one of
+/// mutually recursive types such as expressions holding `&Stmt`s and
+/// statements holding `&Expr`s, but values cannot point back to their
+/// ancestors, nor be shared except through `BackRef`s to values exhumed
+/// earlier.
+///
+/// The input stays borrowed for as long as the value is, so it cannot be
+/// reused meanwhile:
+///
+/// ```compile_fail
+/// let mut input = [0u8; 4];
+/// let value = ignominie::decode::<u32>(&mut input).unwrap();
+/// input[0] = 1;
+/// assert_eq!(*value, 0);
+/// ```
 pub fn decode<'input, T>(input: &'input mut [u8]) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    decode_root(Heap::new(input))
+}
+
+/// Decodes a `T` like `decode`, from an input whose integers are stored
+/// in little-endian byte order, swapping them in place on big-endian hosts.
+pub fn decode_le<'input, T>(input: &'input mut [u8]) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.swap = cfg!(target_endian = "big");
+    heap.swap_words = heap.swap;
+    decode_root(heap)
+}
+
+/// Decodes a `T` like `decode_le`, but from big-endian integers.
+pub fn decode_be<'input, T>(input: &'input mut [u8]) -> Result<&'input T, Error>
 where
     T: Exhume<'input>,
 {
     let mut heap = Heap::new(input);
+    heap.swap = cfg!(target_endian = "little");
+    heap.swap_words = heap.swap;
+    decode_root(heap)
+}
+
+/// Decodes a `T` like `decode`, from an input whose offsets and lengths of
+/// pointers are stored in the byte order `words` and everything else in
+/// the byte order of the host, leaving other integers to the caller.
+pub fn decode_header_endian<'input, T>(
+    input: &'input mut [u8],
+    words: Endianness,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.swap_words = !words.is_native();
+    decode_root(heap)
+}
+
+fn decode_root<'input, T>(mut heap: Heap<'input>) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let ptr = heap.reserve::<T>(0, 1)?;
+    unsafe {
+        T::exhume(ptr, &mut heap)?;
+        Ok(&*ptr)
+    }
+}
+
+/// Decodes a `T` from the start of `header`, whose references point into
+/// `data` rather than into `header`, with offsets counted from the start
+/// of `data`.
+///
+/// As offset 0 is the first byte of `data`, null pointers, such as the
+/// `None`s of `Option<&T>`, are stored as `usize::MAX` in `header`.
+///
+/// `data` is shared, so only the types whose `Exhume::READ_ONLY` is `Some`
+/// may be stored there, and integers must be in the byte order of the host.
+pub fn decode_split_sections<'input, T>(
+    header: &'input mut [u8],
+    data: &'input [u8],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(header);
     let ptr = heap.reserve::<T>(0, 1)?;
+    heap.read_only(data);
+    heap.null = usize::MAX;
     unsafe {
         T::exhume(ptr, &mut heap)?;
         Ok(&*ptr)
     }
 }
 
+/// Views `bytes` as a slice of `T`, e.g. a decoded `&[u8]` whose contents
+/// are known to be integers, failing if they are misaligned, if their
+/// length is not a multiple of the size of `T`, or if any of the values
+/// is invalid. Zero-sized types cannot be viewed.
+///
+/// `bytes` is shared, so only the types whose `Exhume::READ_ONLY` is `Some`
+/// can be viewed, and integers must be in the byte order of the host.
+pub fn view_as<'input, T>(bytes: &'input [u8]) -> Result<&'input [T], Error>
+where
+    T: Exhume<'input>,
+{
+    let size = mem::size_of::<T>();
+    if bytes.len().checked_rem(size).ok_or(error::basic())? != 0 {
+        return Err(error::trailing_bytes());
+    }
+    let len = bytes.len() / size;
+    let mut heap = Heap::new(&mut []);
+    heap.read_only(bytes);
+    let ptr = heap.reserve::<T>(0, len)?;
+    unsafe {
+        T::exhume_slice(ptr, len, &mut heap)?;
+        Ok(slice::from_raw_parts(ptr, len))
+    }
+}
+
+/// Decodes a `T` like `decode`, additionally rejecting the floating-point
+/// values `floats` asks for.
+pub fn decode_strict<'input, T>(
+    input: &'input mut [u8],
+    floats: StrictFloats,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.floats = floats;
+    decode_root(heap)
+}
+
+/// Floating-point values to reject on top of signaling NaNs, which are
+/// always rejected. The default rejects nothing more.
+///
+/// ```ignore
+/// decode_strict::<f32>(input, StrictFloats::default().subnormals())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StrictFloats {
+    subnormals: bool,
+    nan_payloads: bool,
+}
+
+impl StrictFloats {
+    /// Rejects subnormal values, zeroes being allowed.
+    pub fn subnormals(mut self) -> Self {
+        self.subnormals = true;
+        self
+    }
+
+    /// Rejects NaNs with any mantissa bit set besides the quiet bit, as
+    /// NaN-boxed values have, quiet or not. Canonical NaNs are allowed.
+    pub fn nan_payloads(mut self) -> Self {
+        self.nan_payloads = true;
+        self
+    }
+
+    pub(crate) fn rejects_subnormals(&self) -> bool {
+        self.subnormals
+    }
+
+    pub(crate) fn rejects_nan_payloads(&self) -> bool {
+        self.nan_payloads
+    }
+}
+
+/// Decodes a `T` like `decode`, then checks it with `Validate::finalize`.
+pub fn decode_validated<'input, T>(
+    input: &'input mut [u8],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input> + Validate,
+{
+    let value = decode::<T>(input)?;
+    value.finalize()?;
+    Ok(value)
+}
+
+/// Returns the smallest input `decode::<T>` can succeed on.
+///
+/// This is exact for types stored entirely inline, and only a lower bound
+/// for those with references, whose pointees take up more room.
+pub const fn min_size<T>() -> usize {
+    mem::size_of::<T>()
+}
+
+/// Returns where the elements of `decoded` lie in `buffer`, or `None` if
+/// they are not all within it.
+///
+/// `buffer` is a raw pointer as the input stays borrowed for as long as
+/// values decoded from it are around.
+pub fn byte_range_of<T>(
+    decoded: &[T],
+    buffer: *const [u8],
+) -> Option<Range<usize>> {
+    let start = (decoded.as_ptr() as usize)
+        .checked_sub(buffer as *const u8 as usize)?;
+    let end = start.checked_add(mem::size_of_val(decoded))?;
+    if end > buffer.len() {
+        return None;
+    }
+    Some(start..end)
+}
+
+/// Decodes a `T` like `decode`, from any buffer exposing its bytes.
+pub fn decode_buf<'input, B, T>(buf: &'input mut B) -> Result<&'input T, Error>
+where
+    B: AsMut<[u8]> + ?Sized,
+    T: Exhume<'input>,
+{
+    decode(buf.as_mut())
+}
+
+/// Decodes a `T` like `decode`, from the first `filled` bytes of a buffer
+/// which may not be initialised past them, the rest being left alone.
+///
+/// # Safety
+///
+/// The first `filled` bytes of `input` must be initialised.
+pub unsafe fn decode_uninit<'input, T>(
+    input: &'input mut [MaybeUninit<u8>],
+    filled: usize,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let input = input.get_mut(..filled).ok_or(error::out_of_bounds())?;
+    decode(slice::from_raw_parts_mut(input.as_mut_ptr() as *mut u8, filled))
+}
+
+/// Decodes a `T` like `decode`, from an input starting with its own length
+/// as a little-endian `u32`, the root following at the next offset suitably
+/// aligned for `T`. Offsets are counted from the root.
+pub fn decode_prefixed<'input, T>(
+    input: &'input mut [u8],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut prefix = [0; 4];
+    prefix.copy_from_slice(input.get(..4).ok_or(error::out_of_bounds())?);
+    if u32::from_le_bytes(prefix) as usize != input.len() {
+        return Err(error::basic());
+    }
+    let start = 4usize.next_multiple_of(mem::align_of::<T>());
+    decode(input.get_mut(start..).ok_or(error::out_of_bounds())?)
+}
+
+/// Decodes a `T` like `decode`, from an input starting with `header_len`
+/// bytes which are left alone, the root following at the next offset
+/// suitably aligned for `T`. Offsets are counted from the root.
+pub fn decode_after_header<'input, T>(
+    input: &'input mut [u8],
+    header_len: usize,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let start = header_len
+        .checked_next_multiple_of(mem::align_of::<T>())
+        .ok_or(error::overflow())?;
+    decode(input.get_mut(start..).ok_or(error::out_of_bounds())?)
+}
+
+/// Decodes a `T` like `decode`, also zeroing the bytes of the input which
+/// are not part of any value, i.e. those between values and after the last
+/// one, so that inputs with the same values laid out at the same offsets
+/// end up identical.
+///
+/// Padding within values is left alone, as only their `Exhume` impls know
+/// where it lies.
+pub fn decode_canonicalize<'input, T>(
+    input: &'input mut [u8],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.tracking = true;
+    heap.zero_gaps = true;
+    let ptr = heap.reserve::<T>(0, 1)?;
+    unsafe {
+        T::exhume(ptr, &mut heap)?;
+        let tail = heap.end as usize - heap.remaining as usize;
+        ptr::write_bytes(heap.remaining, 0, tail);
+        Ok(&*ptr)
+    }
+}
+
+/// Decodes a `T` like `decode`, translating failures with `map_err`.
+pub fn decode_with<'input, T, E, F>(
+    input: &'input mut [u8],
+    map_err: F,
+) -> Result<&'input T, E>
+where
+    T: Exhume<'input>,
+    F: Fn(Error) -> E,
+{
+    decode(input).map_err(map_err)
+}
+
+/// Decodes a `T` like `decode`, but fails unless less than `align_to` bytes
+/// are left unused at the end of the input, i.e. unless they are only
+/// there to pad it to a multiple of `align_to`.
+///
+/// An `align_to` of 0 is taken as 1, both leaving no room for padding.
+pub fn decode_with_padding<'input, T>(
+    input: &'input mut [u8],
+    align_to: usize,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    let ptr = heap.reserve::<T>(0, 1)?;
+    unsafe {
+        T::exhume(ptr, &mut heap)?;
+        if heap.end as usize - heap.remaining as usize >= align_to.max(1) {
+            return Err(error::trailing_bytes());
+        }
+        Ok(&*ptr)
+    }
+}
+
+/// Decodes a `T` like `decode`, but fails once more than `budget` units of
+/// work were spent, each reservation costing one unit plus one per value
+/// reserved, zero-sized ones included.
+pub fn decode_with_budget<'input, T>(
+    input: &'input mut [u8],
+    budget: usize,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.tracking = true;
+    heap.budget = budget;
+    decode_root(heap)
+}
+
+/// Decodes a `T` like `decode`, but fails with `ErrorKind::DepthExceeded`
+/// when following more than `max_depth` nested pointers, which exhuming
+/// does recursively, to bound how much stack it uses.
+///
+/// Values stored inline, such as fields and array elements, do not count,
+/// as their nesting is bounded by their types already.
+pub fn decode_with_depth<'input, T>(
+    input: &'input mut [u8],
+    max_depth: usize,
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.tracking = true;
+    heap.depth = max_depth;
+    decode_root(heap)
+}
+
+/// Decodes a `T` like `decode`, resolving the indices of `PoolStr`s to the
+/// strings of `pool`.
+pub fn decode_with_pool<'input, T>(
+    input: &'input mut [u8],
+    pool: &'input [&'input str],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.pool = pool;
+    decode_root(heap)
+}
+
+/// Decodes a `T` like `decode`, also returning how the input was used.
+pub fn decode_stats<'input, T>(
+    input: &'input mut [u8],
+) -> Result<(&'input T, DecodeStats), Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.tracking = true;
+    let ptr = heap.reserve::<T>(0, 1)?;
+    unsafe {
+        T::exhume(ptr, &mut heap)?;
+        let stats = DecodeStats {
+            bytes_consumed: heap.consumed,
+            reservation_count: heap.reservations,
+            max_offset: heap.remaining as usize - heap.start as usize,
+        };
+        Ok((&*ptr, stats))
+    }
+}
+
+/// Decodes a `T` like `decode`, also returning the number of values or
+/// slices of values reserved, root included, i.e. one per reference or
+/// slice relocated plus one.
+///
+/// This is `DecodeStats::reservation_count` on its own, for metrics.
+pub fn decode_metered<'input, T>(
+    input: &'input mut [u8],
+) -> Result<(&'input T, usize), Error>
+where
+    T: Exhume<'input>,
+{
+    decode_stats(input).map(|(value, stats)| (value, stats.reservation_count))
+}
+
+/// How `decode_stats` used its input.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DecodeStats {
+    /// The number of bytes reserved for values, padding excluded.
+    pub bytes_consumed: usize,
+    /// The number of values or slices of values reserved, root included.
+    pub reservation_count: usize,
+    /// The offset right past the last reserved value.
+    pub max_offset: usize,
+}
+
+/// Decodes a `T` like `decode`, but carries on after values which are
+/// invalid without affecting the rest of the traversal, such as bad UTF-8
+/// or enum tags, and reports all of them. Errors about the layout of the
+/// input, such as out of bounds offsets, still stop decoding.
+///
+/// The `T` is only returned if no value was invalid, as exhuming values
+/// after an invalid one does not make them valid, see `Heap::rejected`.
+#[cfg(feature = "std")]
+pub fn decode_collect_errors<'input, T>(
+    input: &'input mut [u8],
+) -> Result<&'input T, Vec<Error>>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.errors = Some(Vec::new());
+    let ptr = heap.reserve::<T>(0, 1).map_err(|error| vec![error])?;
+    let result = unsafe { T::exhume(ptr, &mut heap) };
+    let mut errors = heap.errors.take().unwrap_or_default();
+    errors.extend(result.err());
+    if errors.is_empty() {
+        unsafe { Ok(&*ptr) }
+    } else {
+        Err(errors)
+    }
+}
+
+/// Decodes a `T` like `decode`, keeping track of every value exhumed so
+/// that `BackRef`s can point to them.
+#[cfg(feature = "std")]
+pub fn decode_with_back_refs<'input, T>(
+    input: &'input mut [u8],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input>,
+{
+    let mut heap = Heap::new(input);
+    heap.reserved = Some(Vec::new());
+    decode_root(heap)
+}
+
 pub struct Heap<'input> {
     start: *mut u8,
     remaining: *mut u8,
     end: *mut u8,
+    floats: StrictFloats,
+    swap: bool,
+    swap_words: bool,
+    read_only: bool,
+    /// The offset standing for null pointers.
+    null: usize,
+    /// Whether the budget, depth, statistics and zeroing of gaps below are
+    /// in use, so that plain decoding skips their bookkeeping.
+    tracking: bool,
+    zero_gaps: bool,
+    consumed: usize,
+    reservations: usize,
+    budget: usize,
+    depth: usize,
+    pool: &'input [&'input str],
+    #[cfg(feature = "std")]
+    errors: Option<Vec<Error>>,
+    #[cfg(feature = "std")]
+    reserved: Option<Vec<Reservation>>,
     marker: PhantomData<&'input mut ()>,
 }
 
@@ -30,49 +513,607 @@ impl<'input> Heap<'input> {
         Heap {
             start,
             remaining: start,
-            end: unsafe { start.offset(input.len() as isize) },
+            end: unsafe { start.add(input.len()) },
+            floats: StrictFloats::default(),
+            swap: false,
+            swap_words: false,
+            read_only: false,
+            null: 0,
+            tracking: false,
+            zero_gaps: false,
+            consumed: 0,
+            reservations: 0,
+            budget: usize::MAX,
+            depth: usize::MAX,
+            pool: &[],
+            #[cfg(feature = "std")]
+            errors: None,
+            #[cfg(feature = "std")]
+            reserved: None,
             marker: PhantomData,
         }
     }
 
-    fn reserve<T>(
+    /// Makes all further reservations in `data` rather than in the input,
+    /// failing for types whose exhuming would write to it.
+    fn read_only(&mut self, data: &'input [u8]) {
+        let start = data.as_ptr() as *mut u8;
+        self.start = start;
+        self.remaining = start;
+        self.end = unsafe { start.add(data.len()) };
+        self.read_only = true;
+    }
+
+    pub(crate) fn floats(&self) -> StrictFloats {
+        self.floats
+    }
+
+    /// Converts the integer at `ptr` to the byte order of the host in
+    /// place, if the input was not already in it, and returns it.
+    ///
+    /// `ptr` does not need to be aligned, as fields of packed types are
+    /// not, and some targets fault on unaligned loads.
+    pub(crate) unsafe fn native<T>(&self, ptr: *mut T) -> T
+    where
+        T: SwapBytes,
+    {
+        let value = self.to_native(ptr::read_unaligned(ptr));
+        if self.swap {
+            ptr::write_unaligned(ptr, value);
+        }
+        value
+    }
+
+    /// Reads the integer at `ptr` in the byte order of the host like
+    /// `native`, but leaves it as stored, for lengths read out of values
+    /// which are yet to be exhumed, as by `ExhumeUnsized::tail_len`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` points to the initialised bytes of a `T`.
+    pub unsafe fn read_native<T>(&self, ptr: *const T) -> T
+    where
+        T: SwapBytes,
+    {
+        self.to_native(ptr::read_unaligned(ptr))
+    }
+
+    /// Converts the offset or length of a pointer at `ptr` like `native`,
+    /// which may be stored in another byte order than other integers.
+    pub(crate) unsafe fn native_word<T>(&self, ptr: *mut T) -> T
+    where
+        T: SwapBytes,
+    {
+        let value = self.to_native_word(ptr::read_unaligned(ptr));
+        if self.swap_words {
+            ptr::write_unaligned(ptr, value);
+        }
+        value
+    }
+
+    /// Returns whether a value was found invalid while `decode_collect_errors`
+    /// carried on past it. Values exhumed since may then be invalid although
+    /// exhuming them succeeded, so they must not be read.
+    pub fn rejected(&self) -> bool {
+        #[cfg(feature = "std")]
+        if let Some(ref errors) = self.errors {
+            return !errors.is_empty();
+        }
+        false
+    }
+
+    /// Returns whether the offset of a pointer, once converted by
+    /// `native_word`, stands for the null pointer.
+    pub(crate) fn is_null(&self, offset: usize) -> bool {
+        offset == self.null
+    }
+
+    /// Returns whether the offset of a pointer at `ptr`, not converted yet,
+    /// stands for the null pointer, which it is then rewritten as, as
+    /// `Option`s of pointers need.
+    pub(crate) unsafe fn take_null(&self, ptr: *mut usize) -> bool {
+        let offset = self.to_native_word(ptr::read_unaligned(ptr));
+        if !self.is_null(offset) {
+            return false;
+        }
+        ptr::write_unaligned(ptr, 0);
+        true
+    }
+
+    /// Reports that the value being exhumed is invalid, which is an error
+    /// unless `decode_collect_errors` is collecting them.
+    pub(crate) fn invalid(&mut self) -> Result<(), Error> {
+        self.reject(error::basic())
+    }
+
+    /// Reports that the value being exhumed is invalid like `invalid`, but
+    /// with a more specific error.
+    pub(crate) fn reject(&mut self, error: Error) -> Result<(), Error> {
+        #[cfg(feature = "std")]
+        if let Some(ref mut errors) = self.errors {
+            errors.push(error);
+            return Ok(());
+        }
+        Err(error)
+    }
+
+    /// Converts `value`, read from the input, to the byte order of the host.
+    pub(crate) fn to_native<T>(&self, value: T) -> T
+    where
+        T: SwapBytes,
+    {
+        if self.swap { value.swap_bytes() } else { value }
+    }
+
+    /// Converts `value` like `to_native`, for an offset or length of a
+    /// pointer.
+    pub(crate) fn to_native_word<T>(&self, value: T) -> T
+    where
+        T: SwapBytes,
+    {
+        if self.swap_words { value.swap_bytes() } else { value }
+    }
+
+    pub(crate) fn reserve<T>(
         &mut self,
         offset: usize,
         len: usize,
-    ) -> Result<*mut T, Error> {
-        let ptr =
-            (self.start as usize).checked_add(offset).ok_or(error::basic())?;
+    ) -> Result<*mut T, Error>
+    where
+        T: Exhume<'input>,
+    {
+        if self.tracking {
+            self.budget = self
+                .budget
+                .checked_sub(len.saturating_add(1))
+                .ok_or(error::limit_exceeded())?;
+        }
+        let ptr = (self.start as usize)
+            .checked_add(offset)
+            .ok_or(error::overflow())?;
+        // Values must lie past the ones reserved before them, so no byte is
+        // ever reserved twice, e.g. for two references aliasing each other.
         if ptr < self.remaining as usize {
+            return Err(error::overlap());
+        }
+        // Zero-sized values take no bytes, so their offsets do not need to
+        // be aligned, which the start of an empty input seldom is. They are
+        // given a dangling pointer instead.
+        let misaligned = ptr % mem::align_of::<T>() != 0;
+        if misaligned && mem::size_of::<T>() != 0 {
             return Err(error::basic());
         }
-        if ptr % mem::align_of::<T>() != 0 {
+        if self.read_only && T::READ_ONLY.is_none() {
             return Err(error::basic());
         }
-        let byte_len =
-            len.checked_mul(mem::size_of::<T>()).ok_or(error::basic())?;
-        let remaining = ptr.checked_add(byte_len).ok_or(error::basic())?;
+        let byte_len = len
+            .checked_mul(mem::size_of::<T>())
+            .ok_or(error::overflow())?;
+        let remaining = ptr.checked_add(byte_len).ok_or(error::overflow())?;
         if remaining > self.end as usize {
-            return Err(error::basic());
+            return Err(error::out_of_bounds());
+        }
+        if self.tracking {
+            if self.zero_gaps {
+                let gap = ptr - self.remaining as usize;
+                unsafe { ptr::write_bytes(self.remaining, 0, gap) };
+            }
+            self.consumed += byte_len;
+            self.reservations += 1;
         }
         self.remaining = remaining as *mut u8;
+        if misaligned {
+            return Ok(ptr::NonNull::dangling().as_ptr());
+        }
         Ok(ptr as *mut T)
     }
+
+    /// Reserves `len` values of type `T` at `offset` from the start of the
+    /// input, and lets `f` exhume them through a `Region`.
+    ///
+    /// Bounds and alignment are checked before `f` is called, and the
+    /// elements can only be reached once exhumed, in order. Implementors
+    /// of `Exhume` using this to relocate a container remain responsible
+    /// for writing the relocated pointer back in the layout of their type.
+    pub fn region<T, R, F>(
+        &mut self,
+        offset: usize,
+        len: usize,
+        f: F,
+    ) -> Result<R, Error>
+    where
+        T: Exhume<'input> + 'input,
+        F: for<'a> FnOnce(Region<'a, 'input, T>) -> Result<R, Error>,
+    {
+        let ptr = self.reserve::<T>(offset, len)?;
+        f(Region { ptr, len, exhumed: 0, error: None, heap: self })
+    }
+
+    /// Calls `f` to exhume the pointee of a pointer, one level deeper,
+    /// failing past the maximum depth of `decode_with_depth`.
+    pub(crate) fn descend<R, F>(&mut self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Self) -> Result<R, Error>,
+    {
+        if !self.tracking {
+            return f(self);
+        }
+        self.depth = self.depth.checked_sub(1).ok_or(error::depth_exceeded())?;
+        let result = f(self);
+        self.depth += 1;
+        result
+    }
+
+    /// Calls `f` to exhume the element at `index` of a slice or array,
+    /// attaching the index to the errors it reports.
+    pub(crate) fn element<F>(&mut self, index: usize, f: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Self) -> Result<(), Error>,
+    {
+        #[cfg(feature = "std")]
+        let collected = self.errors.as_ref().map_or(0, Vec::len);
+        let result = f(self);
+        #[cfg(feature = "std")]
+        if let Some(ref mut errors) = self.errors {
+            for error in &mut errors[collected..] {
+                *error = error.at_index(index);
+            }
+        }
+        result.map_err(|error| error.at_index(index))
+    }
+
+    /// Returns the string at `index` in the pool of `decode_with_pool`.
+    pub(crate) fn pooled(&self, index: usize) -> Option<&'input &'input str> {
+        self.pool.get(index)
+    }
+
+    /// Reserves like `reserve`, but with `offset` counted from `base`
+    /// rather than from the start of the input.
+    pub(crate) fn reserve_relative<T>(
+        &mut self,
+        base: *const u8,
+        offset: isize,
+        len: usize,
+    ) -> Result<*mut T, Error>
+    where
+        T: Exhume<'input>,
+    {
+        let ptr = (base as usize)
+            .checked_add_signed(offset)
+            .ok_or(error::overflow())?;
+        let offset = ptr
+            .checked_sub(self.start as usize)
+            .ok_or(error::out_of_bounds())?;
+        self.reserve(offset, len)
+    }
+
+    /// Reserves like `reserve`, but with `delta` counted from the end of the
+    /// last reservation rather than from the start of the input.
+    pub(crate) fn reserve_delta<T>(
+        &mut self,
+        delta: usize,
+        len: usize,
+    ) -> Result<*mut T, Error>
+    where
+        T: Exhume<'input>,
+    {
+        let offset = (self.remaining as usize - self.start as usize)
+            .checked_add(delta)
+            .ok_or(error::overflow())?;
+        self.reserve(offset, len)
+    }
+
+    /// Records that the `len` values of type `T` at `ptr`, reserved by
+    /// `reserve`, were exhumed, for `BackRef`s to point to them.
+    ///
+    /// Misaligned zero-sized values have a dangling pointer rather than one
+    /// within the input, and are not recorded.
+    #[cfg(feature = "std")]
+    pub(crate) fn exhumed<T>(&mut self, ptr: *const T, len: usize) {
+        let reserved = match self.reserved {
+            Some(ref mut reserved) => reserved,
+            None => return,
+        };
+        let offset = match (ptr as usize).checked_sub(self.start as usize) {
+            Some(offset) => offset,
+            None => return,
+        };
+        // Pointees are exhumed before the values pointing to them, which
+        // were reserved before them, so this is seldom at the end.
+        let i = reserved.partition_point(|r| r.offset <= offset);
+        reserved.insert(i, Reservation {
+            offset,
+            byte_len: len * mem::size_of::<T>(),
+            type_id: type_id::of::<T>(),
+        });
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn exhumed<T>(&mut self, _ptr: *const T, _len: usize) {}
+
+    /// Returns the `T` at `offset` from the start of the input, which must
+    /// have been exhumed already as one, for `BackRef`.
+    #[cfg(feature = "std")]
+    pub(crate) fn reserved<T>(&self, offset: usize) -> Result<*mut T, Error> {
+        let reserved = self.reserved.as_ref().ok_or(error::basic())?;
+        let size = mem::size_of::<T>();
+        let type_id = type_id::of::<T>();
+        // Reservations are sorted by offset and do not overlap, so the ones
+        // which may contain `offset` are the last ones starting before it.
+        let end = reserved.partition_point(|r| r.offset <= offset);
+        let found = reserved[..end]
+            .iter()
+            .rev()
+            .take_while(|r| r.offset + r.byte_len >= offset)
+            .any(|r| {
+                let skip = offset - r.offset;
+                r.type_id == type_id
+                    && if size == 0 {
+                        skip == 0
+                    } else {
+                        skip < r.byte_len && skip.is_multiple_of(size)
+                    }
+            });
+        if !found {
+            return Err(error::basic());
+        }
+        Ok(unsafe { self.start.add(offset) as *mut T })
+    }
+}
+
+/// Values exhumed after being reserved by `reserve`, recorded for
+/// `BackRef`.
+#[cfg(feature = "std")]
+struct Reservation {
+    offset: usize,
+    byte_len: usize,
+    type_id: TypeId,
+}
+
+/// Integers whose byte order `Heap` can convert.
+pub trait SwapBytes: Copy {
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! swap_bytes_impl {
+    ($($ty:ty,)+) => {
+        $(impl SwapBytes for $ty {
+            fn swap_bytes(self) -> Self {
+                <$ty>::swap_bytes(self)
+            }
+        })+
+    };
+}
+
+swap_bytes_impl! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+}
+
+/// Values reserved by `Heap::region`, waiting to be exhumed.
+pub struct Region<'a, 'input: 'a, T: 'input> {
+    ptr: *mut T,
+    len: usize,
+    exhumed: usize,
+    /// The error of the first value which failed to be exhumed, if any.
+    error: Option<Error>,
+    heap: &'a mut Heap<'input>,
 }
 
+impl<'a, 'input, T> Region<'a, 'input, T>
+where
+    T: Exhume<'input>,
+{
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Exhumes the next value of the region and returns it, or `None` if
+    /// all of them already were.
+    ///
+    /// Once a value failed to be exhumed, the region is poisoned, and this
+    /// and `finish` keep returning its error.
+    pub fn exhume_next(&mut self) -> Option<Result<&T, Error>> {
+        if let Some(error) = self.error {
+            return Some(Err(error));
+        }
+        if self.exhumed == self.len {
+            return None;
+        }
+        unsafe {
+            let ptr = self.ptr.add(self.exhumed);
+            self.exhumed += 1;
+            match self.heap.descend(|heap| T::exhume(ptr, heap)) {
+                Ok(()) => {
+                    self.heap.exhumed(ptr, 1);
+                    Some(Ok(&*ptr))
+                },
+                Err(error) => {
+                    self.error = Some(error);
+                    Some(Err(error))
+                },
+            }
+        }
+    }
+
+    /// Exhumes the remaining values of the region and returns all of them.
+    pub fn finish(mut self) -> Result<&'input [T], Error> {
+        while let Some(result) = self.exhume_next() {
+            result?;
+        }
+        unsafe { Ok(slice::from_raw_parts(self.ptr, self.len)) }
+    }
+}
+
+/// Self-contained segments of an input, each decoded like `decode` with
+/// offsets counted from its own start.
+///
+/// Segments are split off the input in increasing order, so that any number
+/// of them can be decoded and borrowed at once.
+pub struct Segments<'input> {
+    rest: &'input mut [u8],
+    consumed: usize,
+}
+
+impl<'input> Segments<'input> {
+    pub fn new(input: &'input mut [u8]) -> Self {
+        Segments { rest: input, consumed: 0 }
+    }
+
+    /// Decodes a `T` from the `range` of the input, which must start at or
+    /// after the end of the previous segment.
+    pub fn decode<T>(&mut self, range: Range<usize>) -> Result<&'input T, Error>
+    where
+        T: Exhume<'input>,
+    {
+        let start = range.start.checked_sub(self.consumed)
+            .ok_or(error::overlap())?;
+        let len = range.end.checked_sub(range.start)
+            .ok_or(error::out_of_bounds())?;
+        if start > self.rest.len() || len > self.rest.len() - start {
+            return Err(error::out_of_bounds());
+        }
+        let rest = mem::take(&mut self.rest);
+        let (segment, rest) = rest[start..].split_at_mut(len);
+        self.rest = rest;
+        self.consumed = range.end;
+        decode(segment)
+    }
+}
+
+/// Fixed-size records stored one after the other from the start of the
+/// input, exhumed one at a time as they are iterated over, so that those
+/// never reached are never validated.
+///
+/// Bounds and alignment of all records are checked upfront, and what they
+/// point to must come after the last one. Iteration ends after the first
+/// record failing to be exhumed, as not all of its pointees may have been
+/// reserved, so those of the next records could overlap them undetected.
+pub struct RecordCursor<'input, T: 'input> {
+    heap: Heap<'input>,
+    ptr: *mut T,
+    len: usize,
+    exhumed: usize,
+}
+
+impl<'input, T> RecordCursor<'input, T>
+where
+    T: Exhume<'input>,
+{
+    /// Reserves `len` records from the start of `input`.
+    pub fn new(input: &'input mut [u8], len: usize) -> Result<Self, Error> {
+        let mut heap = Heap::new(input);
+        let ptr = heap.reserve::<T>(0, len)?;
+        Ok(RecordCursor { heap, ptr, len, exhumed: 0 })
+    }
+
+    /// Returns the number of records not iterated over yet.
+    pub fn remaining(&self) -> usize {
+        self.len - self.exhumed
+    }
+}
+
+impl<'input, T> Iterator for RecordCursor<'input, T>
+where
+    T: Exhume<'input>,
+{
+    type Item = Result<&'input T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhumed == self.len {
+            return None;
+        }
+        unsafe {
+            let ptr = self.ptr.add(self.exhumed);
+            self.exhumed += 1;
+            let result = T::exhume(ptr, &mut self.heap);
+            if result.is_err() {
+                self.exhumed = self.len;
+            }
+            Some(result.map(|()| &*ptr))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
+
+impl<'input, T> FusedIterator for RecordCursor<'input, T>
+where
+    T: Exhume<'input>,
+{
+}
+
+/// Exhumes the `len` values of type `T` at `offset` one at a time, passing
+/// each to `f` as soon as it is, and stopping early once `f` returns
+/// `false`, like a `RecordCursor` not starting at the start of the input.
+///
+/// What the values point to must come after the last one.
+pub fn validate_slice_streaming<'input, T, F>(
+    input: &'input mut [u8],
+    offset: usize,
+    len: usize,
+    mut f: F,
+) -> Result<(), Error>
+where
+    T: Exhume<'input> + 'input,
+    F: FnMut(&'input T) -> bool,
+{
+    let mut heap = Heap::new(input);
+    let ptr = heap.reserve::<T>(offset, len)?;
+    for i in 0..len {
+        unsafe {
+            let ptr = ptr.add(i);
+            T::exhume(ptr, &mut heap)?;
+            if !f(&*ptr) {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// As a root, e.g. in `decode::<&T>`, the first word of the input is the
+/// offset of the `T`, which must then come after that word.
+///
+/// Each pointee is reserved past all the values reserved before it, so the
+/// references of a decoded value never share any byte, e.g. the fields of
+/// `(&u32, &u32)`, and input making two of them point to the same offset
+/// fails with `ErrorKind::Overlap`. Only `BackRef`s may point to values
+/// reserved by other references.
 impl<'input, T> Exhume<'input> for &'input T
 where
     T: Exhume<'input>,
 {
+    const IS_RELOCATION_FREE: bool = false;
+
     unsafe fn exhume(
         this: *mut Self,
         heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, usize>;
-        if (*(this as *const *const T)).is_null() {
-            return Err(error::basic());
+        let offset = heap.native_word(this as *mut usize);
+        if heap.is_null(offset) {
+            return Err(error::null_pointer(PointerKind::Reference));
         }
-        let ptr = heap.reserve::<T>(*(this as *mut usize), 1)?;
-        T::exhume(ptr, heap)?;
+        let ptr = heap.reserve::<T>(offset, 1)?;
+        heap.descend(|heap| T::exhume(ptr, heap))?;
+        heap.exhumed(ptr, 1);
+        // `this` lies in a region reserved before `ptr`, and `reserve` only
+        // hands out bytes past the previous ones, so the slot we write to
+        // never overlaps the pointee, even when they are adjacent. Pointees
+        // in a read-only section are in another buffer altogether, and
+        // misaligned zero-sized ones get a dangling pointer.
+        debug_assert!(
+            heap.read_only
+                || mem::size_of::<T>() == 0
+                || this as usize + mem::size_of::<Self>() <= ptr as usize
+        );
         *this = &*ptr;
         Ok(())
     }
@@ -82,20 +1123,66 @@ impl<'input, T> Exhume<'input> for &'input [T]
 where
     T: Exhume<'input>,
 {
+    const IS_RELOCATION_FREE: bool = false;
+
     unsafe fn exhume(
         this: *mut Self,
         heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
-        if *(this as *const *const [T]) as *const T == ptr::null::<T>() {
-            return Err(error::basic());
+        let _ = mem::transmute::<Self, [usize; 2]>;
+        let offset = heap.native_word(this as *mut usize);
+        let len = heap.native_word((this as *mut usize).add(1));
+        if heap.is_null(offset) {
+            return Err(error::null_pointer(PointerKind::Slice));
         }
-        let offset = (*this).as_ptr() as usize;
-        let len = (*this).len();
         let ptr = heap.reserve::<T>(offset, len)?;
-        for i in 0..len {
-            T::exhume(ptr.offset(i as isize), heap)?;
-        }
+        heap.descend(|heap| T::exhume_slice(ptr, len, heap))?;
+        heap.exhumed(ptr, len);
+        // See the `&T` impl about why this cannot alias the elements.
+        debug_assert!(
+            heap.read_only
+                || mem::size_of::<T>() == 0
+                || this as usize + mem::size_of::<Self>() <= ptr as usize
+        );
         *this = slice::from_raw_parts(ptr, len);
         Ok(())
     }
 }
+
+/// `None` is the null pointer, which is null in either byte order.
+impl<'input, T> Exhume<'input> for Option<&'input T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, usize>;
+        if heap.take_null(this as *mut usize) {
+            return Ok(());
+        }
+        <&T>::exhume(this as *mut &T, heap)
+    }
+}
+
+/// `None` has a null data pointer, whatever its length is.
+impl<'input, T> Exhume<'input> for Option<&'input [T]>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, [usize; 2]>;
+        if heap.take_null(this as *mut usize) {
+            return Ok(());
+        }
+        <&[T]>::exhume(this as *mut &[T], heap)
+    }
+}
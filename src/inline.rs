@@ -0,0 +1,41 @@
+use Exhume;
+use core::str;
+use error::Error;
+use heap::Heap;
+
+/// A UTF-8 string of at most `N` bytes stored inline, followed by its
+/// length as a `u8`. The bytes past the length are ignored.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct InlineStr<const N: usize> {
+    bytes: [u8; N],
+    len: u8,
+}
+
+impl<const N: usize> InlineStr<N> {
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len()]) }
+    }
+}
+
+impl<'input, const N: usize> Exhume<'input> for InlineStr<N> {
+    const IS_RELOCATION_FREE: bool = true;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        match (*this).bytes.get(..(*this).len()) {
+            Some(bytes) if str::from_utf8(bytes).is_ok() => Ok(()),
+            _ => heap.invalid(),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+/// Decodes a type from a zeroed buffer of the size it was laid out for, and
+/// asserts that its size and the addresses its fields were decoded at are
+/// the ones of that layout, to catch a type drifting from its format, e.g.
+/// when a field is added or reordered.
+///
+/// Types which are invalid when zeroed, such as those holding references,
+/// are decoded from the `input` given instead, whose length is `size`.
+///
+/// ```ignore
+/// #[repr(C)]
+/// struct Record<'input> {
+///     name: Option<&'input [u8]>,
+///     age: u32,
+/// }
+///
+/// #[test]
+/// fn record_layout() {
+///     assert_exhume_layout!(Record<'_> { name: 0, age: 16 }, size = 24);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_exhume_layout {
+    (
+        $ty:ty { $($field:tt: $offset:expr),+ $(,)* },
+        size = $size:expr,
+        input = $input:expr $(,)*
+    ) => {{
+        assert_eq!(
+            $crate::__size_of::<$ty>(),
+            $size,
+            "size of {}",
+            stringify!($ty),
+        );
+        let input: &mut [u8] = $input;
+        let start = input.as_ptr() as usize;
+        let value = match $crate::decode::<$ty>(input) {
+            Ok(value) => value,
+            Err(error) => panic!(
+                "decoding {} failed: {:?}",
+                stringify!($ty),
+                error,
+            ),
+        };
+        $(assert_eq!(
+            &value.$field as *const _ as *const u8 as usize - start,
+            $offset,
+            "offset of {}.{}",
+            stringify!($ty),
+            stringify!($field),
+        );)+
+    }};
+    (
+        $ty:ty { $($field:tt: $offset:expr),+ $(,)* },
+        size = $size:expr $(,)*
+    ) => {{
+        const WORDS: usize = ($size as usize).div_ceil(8);
+        let mut words = [0u64; WORDS];
+        let input = unsafe {
+            $crate::__from_raw_parts_mut(words.as_mut_ptr() as *mut u8, $size)
+        };
+        assert_exhume_layout!(
+            $ty { $($field: $offset,)+ },
+            size = $size,
+            input = input,
+        );
+    }};
+}
@@ -3,16 +3,54 @@
 #[cfg(feature = "std")]
 extern crate core;
 
+mod aligned;
+#[cfg(feature = "std")]
+mod backref;
+mod bitmap;
+mod bitset;
+mod bounded;
+#[cfg(feature = "std")]
+mod boxed;
+mod canonical;
+#[cfg(feature = "std")]
+mod decoder;
+mod delta;
+mod discriminant;
+mod dispatch;
+mod dst;
+mod endian;
 mod error;
+mod flags;
 mod heap;
+mod inline;
+#[cfg(feature = "test-utils")]
+mod layout;
+mod nullable;
+mod offset;
+mod pool;
+pub mod prelude;
+mod pstring;
+mod raw;
+mod rel;
+mod schema;
+#[cfg(feature = "std")]
+mod shared;
+mod tagged;
+mod type_id;
+#[macro_use]
+mod uninhabited;
 
 use core::char;
 use core::cmp::Ordering;
+use core::convert::Infallible;
 use core::marker::PhantomData;
 use core::mem;
-use core::num::{FpCategory, Wrapping};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::num::{FpCategory, Saturating, Wrapping};
 use core::ops::{Range, RangeFrom, RangeFull, RangeTo};
+use core::slice;
 use core::str;
+use core::sync::atomic;
 #[cfg(feature = "std")]
 use std::ffi::{CStr, OsStr};
 #[cfg(feature = "std")]
@@ -23,22 +61,169 @@ use std::os::unix::ffi::OsStrExt;
 use std::panic::AssertUnwindSafe;
 #[cfg(feature = "std")]
 use std::path::Path;
-#[cfg(feature = "std")]
-use std::string::ParseError;
 
-pub use error::Error;
-pub use heap::{Heap, decode};
+pub use aligned::{AlignedBytes, Buffer, decode_aligned, decode_buffer};
+#[cfg(feature = "std")]
+pub use backref::BackRef;
+pub use bitmap::{Bitmap, Optional};
+pub use bitset::{BitSet, Ones, PackedBools};
+pub use bounded::BoundedSlice;
+#[cfg(feature = "std")]
+pub use boxed::BoxedSlice;
+pub use canonical::{CanonicalHash, canonical_hash};
+#[cfg(feature = "std")]
+pub use decoder::Decoder;
+pub use delta::{DeltaPtr, DeltaSlice};
+pub use discriminant::DiscriminantTag;
+pub use dispatch::{Dispatch, ExhumeFn, TaggedRef};
+pub use dst::ExhumeUnsized;
+#[doc(hidden)]
+pub use dst::exhume_unsized as __exhume_unsized;
+pub use endian::{Be, Endianness, Le};
+pub use error::{Error, ErrorKind, NulKind, PointerKind};
+#[doc(hidden)]
+pub use flags::exhume_flags as __exhume_flags;
+#[cfg(feature = "std")]
+pub use heap::{decode_collect_errors, decode_with_back_refs};
+#[doc(hidden)]
+pub use core::result::Result as __Result;
+#[cfg(feature = "test-utils")]
+#[doc(hidden)]
+pub use core::{
+    mem::size_of as __size_of,
+    slice::from_raw_parts_mut as __from_raw_parts_mut,
+};
+pub use heap::{
+    DecodeStats,
+    Heap,
+    RecordCursor,
+    Region,
+    Segments,
+    StrictFloats,
+    byte_range_of,
+    decode,
+    decode_after_header,
+    decode_be,
+    decode_buf,
+    decode_header_endian,
+    decode_canonicalize,
+    decode_le,
+    decode_metered,
+    decode_prefixed,
+    decode_split_sections,
+    decode_stats,
+    decode_strict,
+    decode_uninit,
+    decode_validated,
+    decode_with,
+    decode_with_budget,
+    decode_with_depth,
+    decode_with_padding,
+    decode_with_pool,
+    min_size,
+    validate_slice_streaming,
+    view_as,
+};
+pub use inline::InlineStr;
+pub use nullable::Nullable;
+pub use offset::OffsetRef;
+pub use pool::PoolStr;
+pub use pstring::PString;
+pub use raw::RawSlice;
+pub use rel::{RelPtr, RelSlice};
+pub use schema::{Schema, decode_typed, schema_hash};
+#[cfg(feature = "std")]
+pub use shared::{SharedDecoded, decode_frame};
+pub use tagged::CTaggedOption;
 
+/// Types which can be decoded in place from bytes laid out like them.
+///
+/// Exhuming reads fields where the compiler placed them, so a buffer is
+/// only portable between builds if the layout of its types is specified,
+/// i.e. if they are `#[repr(C)]`, `#[repr(transparent)]` or primitives.
+/// The layout of other types, tuples included, may change with the
+/// compiler or its flags.
 pub trait Exhume<'input> {
+    /// Whether exhuming never rewrites anything in place besides swapping
+    /// byte order, i.e. the type holds no reference nor slice pointer and
+    /// its bytes stay valid wherever the buffer is moved. Exhuming may still
+    /// write, so this does not make it exhumable from shared bytes, see
+    /// `READ_ONLY` for that.
+    const IS_RELOCATION_FREE: bool = false;
+
+    /// Whether values of this type can be exhumed from shared bytes, which
+    /// only unsafe code can vouch for, see `ReadOnly`.
+    const READ_ONLY: Option<ReadOnly> = None;
+
+    /// Validates and relocates the value at `this` in place.
+    ///
+    /// Succeeding only means the value is valid if `heap.rejected()` is
+    /// false, as `decode_collect_errors` carries on past invalid values, so
+    /// impls reading a field after exhuming it must check that first.
+    ///
+    /// # Safety
+    ///
+    /// `this` must point to `size_of::<Self>()` initialised bytes borrowed
+    /// from the buffer `heap` was created from, which must be suitably
+    /// aligned for `Self`.
     unsafe fn exhume(
         this: *mut Self,
         heap: &mut Heap<'input>,
     ) -> Result<(), Error>;
+
+    /// Validates and relocates the `len` values from `this` in place, in
+    /// order, which some types can do faster than one by one.
+    ///
+    /// # Safety
+    ///
+    /// Same as `exhume`, for `len` consecutive values.
+    unsafe fn exhume_slice(
+        this: *mut Self,
+        len: usize,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        for i in 0..len {
+            heap.element(i, |heap| Self::exhume(this.add(i), heap))?;
+        }
+        Ok(())
+    }
+}
+
+/// A promise that exhuming a type neither writes to its bytes nor forms a
+/// `&mut` to them, as long as its integers are in the byte order of the
+/// host, and that its values cannot write to them once decoded either, as
+/// those of types with interior mutability could.
+///
+/// Only types making it can be exhumed from shared bytes, as `view_as` and
+/// `decode_split_sections` do.
+#[derive(Clone, Copy, Debug)]
+pub struct ReadOnly(());
+
+impl ReadOnly {
+    /// # Safety
+    ///
+    /// The `Exhume` impl whose `READ_ONLY` this is must keep the promise.
+    pub const unsafe fn new() -> Self {
+        ReadOnly(())
+    }
+}
+
+/// Checks invariants spanning a whole decoded value, beyond the validity of
+/// its bytes, e.g. that a slice flagged as sorted actually is.
+pub trait Validate {
+    fn finalize(&self) -> Result<(), Error>;
 }
 
 macro_rules! noop_impl {
     ($($ty:ty,)+) => {
         $(impl<'input> Exhume<'input> for $ty {
+            const IS_RELOCATION_FREE: bool = true;
+            const READ_ONLY: Option<ReadOnly> =
+                Some(unsafe { ReadOnly::new() });
+
             unsafe fn exhume(
                 _this: *mut Self,
                 _heap: &mut Heap<'input>,
@@ -53,17 +238,31 @@ noop_impl!(
     (),
     RangeFull,
     u8,
-    u16,
-    u32,
-    u64,
-    usize,
     i8,
-    i16,
-    i32,
-    i64,
-    isize,
+    Ipv4Addr,
+    Ipv6Addr,
 );
 
+macro_rules! int_impl {
+    ($($ty:ty,)+) => {
+        $(impl<'input> Exhume<'input> for $ty {
+            const IS_RELOCATION_FREE: bool = true;
+            const READ_ONLY: Option<ReadOnly> =
+                Some(unsafe { ReadOnly::new() });
+
+            unsafe fn exhume(
+                this: *mut Self,
+                heap: &mut Heap<'input>,
+            ) -> Result<(), Error> {
+                heap.native(this);
+                Ok(())
+            }
+        })+
+    };
+}
+
+int_impl!(u16, u32, u64, usize, i16, i32, i64, isize,);
+
 macro_rules! parameterised_newtype_impl {
     ($($(#[$attr:meta])* $ty:ident,)+) => {
         $($(#[$attr])*
@@ -71,6 +270,8 @@ macro_rules! parameterised_newtype_impl {
         where
             T: Exhume<'input>,
         {
+            const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+
             unsafe fn exhume(
                 this: *mut Self,
                 heap: &mut Heap<'input>,
@@ -85,62 +286,161 @@ macro_rules! parameterised_newtype_impl {
 
 parameterised_newtype_impl!(
     #[cfg(feature = "std")] AssertUnwindSafe,
+    Saturating,
     Wrapping,
 );
 
+macro_rules! atomic_impl {
+    ($($(#[$attr:meta])* $ty:ident: $base:ty,)+) => {
+        $($(#[$attr])*
+        impl<'input> Exhume<'input> for atomic::$ty {
+            const IS_RELOCATION_FREE: bool = true;
+
+            unsafe fn exhume(
+                this: *mut Self,
+                heap: &mut Heap<'input>,
+            ) -> Result<(), Error> {
+                let _ = mem::transmute::<Self, $base>;
+                <$base>::exhume(this as *mut $base, heap)
+            }
+        })+
+    };
+}
+
+// Targets lack the atomics they cannot load and store natively, e.g.
+// thumbv7m-none-eabi has no AtomicU64, so each impl is gated on its width.
+// `cargo build --target thumbv7m-none-eabi` checks that this still builds.
+atomic_impl!(
+    #[cfg(target_has_atomic = "8")] AtomicBool: bool,
+    #[cfg(target_has_atomic = "8")] AtomicU8: u8,
+    #[cfg(target_has_atomic = "8")] AtomicI8: i8,
+    #[cfg(target_has_atomic = "16")] AtomicU16: u16,
+    #[cfg(target_has_atomic = "16")] AtomicI16: i16,
+    #[cfg(target_has_atomic = "32")] AtomicU32: u32,
+    #[cfg(target_has_atomic = "32")] AtomicI32: i32,
+    #[cfg(target_has_atomic = "64")] AtomicU64: u64,
+    #[cfg(target_has_atomic = "64")] AtomicI64: i64,
+    #[cfg(target_has_atomic = "ptr")] AtomicUsize: usize,
+    #[cfg(target_has_atomic = "ptr")] AtomicIsize: isize,
+);
+
 impl<'input> Exhume<'input> for bool {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
     unsafe fn exhume(
         this: *mut Self,
-        _heap: &mut Heap<'input>,
+        heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, u8>;
         let byte = *(this as *const u8);
         if byte == true as u8 || byte == false as u8 {
             Ok(())
         } else {
-            Err(error::basic())
+            heap.invalid()
         }
     }
+
+    unsafe fn exhume_slice(
+        this: *mut Self,
+        len: usize,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let bytes = slice::from_raw_parts(this as *const u8, len);
+        // Branching once per chunk rather than once per byte lets this
+        // vectorise.
+        for (i, chunk) in bytes.chunks(64).enumerate() {
+            if chunk.iter().fold(0, |acc, byte| acc | byte) & !1 != 0 {
+                for (j, &byte) in chunk.iter().enumerate() {
+                    if byte > 1 {
+                        heap.element(i * 64 + j, |heap| heap.invalid())?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'input> Exhume<'input> for f32 {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
     unsafe fn exhume(
         this: *mut Self,
-        _heap: &mut Heap<'input>,
+        heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, u32>;
-        let bits = *(this as *const u32);
-        if bits & 0x1FF << 22 == 0x1FF << 22 && bits & 0x3FFFFF != 0 {
-            // Signaling NaNs are errors.
-            return Err(error::basic());
+        let bits = heap.native(this as *mut u32);
+        // Signaling NaNs, whose quiet bit is clear, are errors.
+        if bits & 0xFF << 23 == 0xFF << 23 &&
+            bits & 1 << 22 == 0 &&
+            bits & 0x7FFFFF != 0
+        {
+            return heap.invalid();
+        }
+        if heap.floats().rejects_subnormals() &&
+            bits & 0xFF << 23 == 0 &&
+            bits & 0x7FFFFF != 0
+        {
+            return heap.invalid();
+        }
+        if heap.floats().rejects_nan_payloads() &&
+            bits & 0xFF << 23 == 0xFF << 23 &&
+            bits & 0x3FFFFF != 0
+        {
+            return heap.invalid();
         }
         Ok(())
     }
 }
 
 impl<'input> Exhume<'input> for f64 {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
     unsafe fn exhume(
         this: *mut Self,
-        _heap: &mut Heap<'input>,
+        heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, u64>;
-        let bits = *(this as *const u64);
-        if bits & 0xFFF << 51 == 0xFFF << 51 && bits & 0xFFFFFFFFFFFFF != 0 {
-            // Signaling NaNs are errors.
-            return Err(error::basic());
+        let bits = heap.native(this as *mut u64);
+        // Signaling NaNs, whose quiet bit is clear, are errors.
+        if bits & 0x7FF << 52 == 0x7FF << 52 &&
+            bits & 1 << 51 == 0 &&
+            bits & 0xFFFFFFFFFFFFF != 0
+        {
+            return heap.invalid();
+        }
+        if heap.floats().rejects_subnormals() &&
+            bits & 0x7FF << 52 == 0 &&
+            bits & 0xFFFFFFFFFFFFF != 0
+        {
+            return heap.invalid();
+        }
+        if heap.floats().rejects_nan_payloads() &&
+            bits & 0x7FF << 52 == 0x7FF << 52 &&
+            bits & 0x7FFFFFFFFFFFF != 0
+        {
+            return heap.invalid();
         }
         Ok(())
     }
 }
 
 impl<'input> Exhume<'input> for char {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
     unsafe fn exhume(
         this: *mut Self,
-        _heap: &mut Heap<'input>,
+        heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, u32>;
-        char::from_u32(*(this as *mut u32)).ok_or(error::basic())?;
-        Ok(())
+        match char::from_u32(heap.native(this as *mut u32)) {
+            Some(_) => Ok(()),
+            None => heap.invalid(),
+        }
     }
 }
 
@@ -152,8 +452,10 @@ impl<'input> Exhume<'input> for &'input str {
         let _ = mem::transmute::<Self, &[u8]>;
         let ptr = this as *mut &[u8];
         <&[u8]>::exhume(ptr, heap)?;
-        str::from_utf8(*ptr).ok().ok_or(error::basic())?;
-        Ok(())
+        match str::from_utf8(*ptr) {
+            Ok(_) => Ok(()),
+            Err(_) => heap.invalid(),
+        }
     }
 }
 
@@ -166,8 +468,12 @@ impl<'input> Exhume<'input> for &'input CStr {
         let _ = mem::transmute::<Self, &[u8]>;
         let ptr = this as *mut &[u8];
         <&[u8]>::exhume(ptr, heap)?;
-        CStr::from_bytes_with_nul(*ptr).ok().ok_or(error::basic())?;
-        Ok(())
+        let bytes = *ptr;
+        match bytes.iter().position(|&byte| byte == 0) {
+            Some(i) if i + 1 == bytes.len() => Ok(()),
+            Some(_) => heap.reject(error::nul(NulKind::Interior)),
+            None => heap.reject(error::nul(NulKind::Missing)),
+        }
     }
 }
 
@@ -193,8 +499,8 @@ impl<'input> Exhume<'input> for &'input Path {
         heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, &OsStr>;
-        let ptr = this as *mut OsStr;
-        OsStr::exhume(ptr, heap)?;
+        let ptr = this as *mut &OsStr;
+        <&OsStr>::exhume(ptr, heap)?;
         let _ = Path::new(*ptr);
         Ok(())
     }
@@ -205,14 +511,19 @@ impl<'input> Exhume<'input> for &'input Path {
         heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         let _ = mem::transmute::<Self, &str>;
-        let ptr = this as *mut str;
+        let ptr = this as *mut &str;
         <&str>::exhume(ptr, heap)?;
-        let _ = Path::new(*ptr);
+        if !heap.rejected() {
+            let _ = Path::new(*ptr);
+        }
         Ok(())
     }
 }
 
 impl<'input, T> Exhume<'input> for PhantomData<T> {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
     unsafe fn exhume(
         _this: *mut Self,
         _heap: &mut Heap<'input>,
@@ -221,21 +532,29 @@ impl<'input, T> Exhume<'input> for PhantomData<T> {
     }
 }
 
+// Only for fieldless enums, whose discriminant is all there is to them:
+// `assert_shape` fails to compile on variants with fields, and the
+// transmute on enums of a different size than `$repr`, so enums whose
+// discriminant lives in a niche or past a payload are rejected.
 macro_rules! c_enum_impl {
     ($(
         $(#[$attr:meta])*
         enum $ty:ident: $repr:ident { $($name:ident,)+ }
     )+) => {
         $($(#[$attr])* impl<'input> Exhume<'input> for $ty {
+            const IS_RELOCATION_FREE: bool = true;
+            const READ_ONLY: Option<ReadOnly> =
+                Some(unsafe { ReadOnly::new() });
+
             #[allow(non_upper_case_globals)]
             unsafe fn exhume(
                 this: *mut Self,
-                _heap: &mut Heap<'input>,
+                heap: &mut Heap<'input>,
             ) -> Result<(), Error> {
                 let _ = mem::transmute::<Self, $repr>;
                 let ptr = this as *mut $repr;
                 #[allow(dead_code)]
-                fn assert_shape<T>(value: $ty) {
+                fn assert_shape(value: $ty) {
                     match value {
                         $($ty::$name => {},)+
                     }
@@ -243,7 +562,7 @@ macro_rules! c_enum_impl {
                 $(const $name: $repr = $ty::$name as $repr;)+
                 match *ptr {
                     $($name => Ok(()),)+
-                    _ => Err(error::basic())
+                    _ => heap.invalid(),
                 }
             }
         })+
@@ -273,26 +592,67 @@ c_enum_impl! {
     }
 }
 
-#[cfg(feature = "std")]
-impl<'input> Exhume<'input> for ParseError {
+impl<'input> Exhume<'input> for IpAddr {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
     unsafe fn exhume(
-        _this: *mut Self,
-        _heap: &mut Heap<'input>,
+        this: *mut Self,
+        heap: &mut Heap<'input>,
     ) -> Result<(), Error> {
         #[allow(dead_code)]
-        fn assert_shape(value: ParseError) {
-            match value {}
+        fn assert_shape(value: IpAddr) {
+            match value {
+                IpAddr::V4(_) | IpAddr::V6(_) => {},
+            }
+        }
+        // Like the Rust-repr enums in c_enum_impl!, this relies on the tag
+        // being the first byte, with the payloads accepting any bytes.
+        fn tag(addr: IpAddr) -> u8 {
+            unsafe { *(&addr as *const IpAddr as *const u8) }
+        }
+        let byte = *(this as *const u8);
+        if byte == tag(IpAddr::V4(Ipv4Addr::UNSPECIFIED)) ||
+            byte == tag(IpAddr::V6(Ipv6Addr::UNSPECIFIED))
+        {
+            Ok(())
+        } else {
+            heap.invalid()
         }
-        Err(error::basic())
     }
 }
 
+impl<'input> Exhume<'input> for Option<Ordering> {
+    const IS_RELOCATION_FREE: bool = true;
+    const READ_ONLY: Option<ReadOnly> = Some(unsafe { ReadOnly::new() });
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, u8>;
+        // None lives in a niche of Ordering, whichever byte that is.
+        fn byte(value: Option<Ordering>) -> u8 {
+            unsafe { *(&value as *const Option<Ordering> as *const u8) }
+        }
+        if *(this as *const u8) == byte(None) {
+            Ok(())
+        } else {
+            Ordering::exhume(this as *mut Ordering, heap)
+        }
+    }
+}
+
+uninhabited_exhume!(Infallible);
+
 macro_rules! range_impl {
     ($($ty:ident { $($name:ident),* })+) => {
         $(impl<'input, T> Exhume<'input> for $ty<T>
         where
             T: Exhume<'input>,
         {
+            const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+
             unsafe fn exhume(
                 this: *mut Self,
                 heap: &mut Heap<'input>,
@@ -312,37 +672,33 @@ range_impl! {
     RangeTo { end }
 }
 
-macro_rules! array_impl {
-    ($($len:expr,)+) => {
-        $(impl<'input, T> Exhume<'input> for [T; $len]
-        where
-            T: Exhume<'input>,
-        {
-            unsafe fn exhume(
-                this: *mut Self,
-                heap: &mut Heap<'input>,
-            ) -> Result<(), Error> {
-                let ptr = this as *mut T;
-                for i in 0..$len {
-                    T::exhume(ptr.offset(i as isize), heap)?;
-                }
-                Ok(())
-            }
-        })+
-    };
-}
+impl<'input, T, const N: usize> Exhume<'input> for [T; N]
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+    const READ_ONLY: Option<ReadOnly> = T::READ_ONLY;
 
-array_impl!(
-    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
-    21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
-);
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        T::exhume_slice(this as *mut T, N, heap)
+    }
+}
 
 macro_rules! tuple_impl {
     ($(($($ty:ident $pos:tt),*),)+) => {
-        $(impl<'input, $($ty),*> Exhume<'input> for ($($ty,)*)
+        $(/// The layout of tuples is unspecified and may differ between
+        /// compilers, so buffers relying on it are not portable. Use a
+        /// `#[repr(C)]` struct such as `Pair` instead.
+        impl<'input, $($ty),*> Exhume<'input> for ($($ty,)*)
         where
             $($ty: Exhume<'input>,)*
         {
+            const IS_RELOCATION_FREE: bool =
+                true $(&& $ty::IS_RELOCATION_FREE)*;
+
             #[allow(non_snake_case)]
             unsafe fn exhume(
                 this: *mut Self,
@@ -369,3 +725,26 @@ tuple_impl! {
     (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
     (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11),
 }
+
+/// An ordered pair with a `#[repr(C)]` layout, i.e. `.0` at offset 0 and
+/// `.1` at the next offset suitably aligned for `B`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Pair<A, B>(pub A, pub B);
+
+impl<'input, A, B> Exhume<'input> for Pair<A, B>
+where
+    A: Exhume<'input>,
+    B: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool =
+        A::IS_RELOCATION_FREE && B::IS_RELOCATION_FREE;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        A::exhume(&mut (*this).0 as *mut A, heap)?;
+        B::exhume(&mut (*this).1 as *mut B, heap)
+    }
+}
@@ -0,0 +1,36 @@
+use Exhume;
+use core::mem;
+use error::Error;
+use heap::Heap;
+
+/// A reference which may be null, for C-style optional pointers.
+///
+/// It is stored and exhumed exactly like `Option<&T>`: a null offset is
+/// absent rather than invalid, and any other one is relocated like `&T`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct Nullable<'input, T: 'input>(Option<&'input T>);
+
+impl<'input, T> Nullable<'input, T> {
+    /// Returns the pointee, or `None` if the reference was null.
+    pub fn as_ref(&self) -> Option<&'input T> {
+        self.0
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<'input, T> Exhume<'input> for Nullable<'input, T>
+where
+    T: Exhume<'input>,
+{
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, Option<&T>>;
+        <Option<&T>>::exhume(this as *mut Option<&T>, heap)
+    }
+}
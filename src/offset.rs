@@ -0,0 +1,55 @@
+use Exhume;
+use core::marker::PhantomData;
+use core::mem;
+use error::Error;
+use heap::Heap;
+
+/// A reference stored as the offset of its pointee from the start of the
+/// input, and left as such once exhumed, so that the buffer can be copied
+/// elsewhere and still be read through the copy.
+///
+/// Only the pointee itself is position-independent: pointers it holds are
+/// relocated as usual, unless they are `OffsetRef`s, `RelPtr`s or the like.
+#[repr(transparent)]
+pub struct OffsetRef<T> {
+    offset: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T> OffsetRef<T> {
+    /// Returns the offset of the pointee from the start of the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the pointee, reading it from `base`.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be the input `self` was decoded from, or a copy of it
+    /// made afterwards, starting at an address suitably aligned for `T`.
+    pub unsafe fn get<'b>(&self, base: &'b [u8]) -> &'b T {
+        debug_assert!(self.offset <= base.len());
+        debug_assert!(base.len() - self.offset >= mem::size_of::<T>());
+        debug_assert!(self.offset.is_multiple_of(mem::align_of::<T>()));
+        &*(base.as_ptr().add(self.offset) as *const T)
+    }
+}
+
+impl<'input, T> Exhume<'input> for OffsetRef<T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let offset = heap.native_word(&mut (*this).offset as *mut usize);
+        let ptr = heap.reserve::<T>(offset, 1)?;
+        heap.descend(|heap| T::exhume(ptr, heap))?;
+        heap.exhumed(ptr, 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use Exhume;
+use core::mem;
+use core::ops::Deref;
+use error::Error;
+use heap::Heap;
+
+/// A string stored as an index into a pool of strings shipped separately
+/// from the input, which many records can then share.
+///
+/// The pool is given to `decode_with_pool`, and an index past its end is
+/// invalid. Anywhere else, the pool is empty, so `PoolStr` always fails.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct PoolStr<'input>(&'input &'input str);
+
+impl<'input> PoolStr<'input> {
+    pub fn as_str(&self) -> &'input str {
+        self.0
+    }
+}
+
+impl<'input> Exhume<'input> for PoolStr<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let _ = mem::transmute::<Self, usize>;
+        let index = heap.native_word(this as *mut usize);
+        match heap.pooled(index) {
+            Some(string) => {
+                *this = PoolStr(string);
+                Ok(())
+            },
+            None => heap.invalid(),
+        }
+    }
+}
+
+impl<'input> Deref for PoolStr<'input> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,42 @@
+//! The items needed to implement `Exhume` and decode values.
+//!
+//! ```
+//! use ignominie::AlignedBytes;
+//! use ignominie::prelude::*;
+//! use std::mem;
+//!
+//! #[repr(C)]
+//! struct Record<'input> {
+//!     name: &'input str,
+//!     age: u32,
+//! }
+//!
+//! impl<'input> Exhume<'input> for Record<'input> {
+//!     unsafe fn exhume(
+//!         this: *mut Self,
+//!         heap: &mut Heap<'input>,
+//!     ) -> Result<(), Error> {
+//!         <&str>::exhume(&mut (*this).name, heap)?;
+//!         u32::exhume(&mut (*this).age, heap)
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Error> {
+//! // A record followed by its name, which it points to by its offset.
+//! let word = mem::size_of::<usize>();
+//! let size = mem::size_of::<Record>();
+//! let mut input = AlignedBytes::<Record, 64>::new([0; 64]);
+//! let bytes = input.as_mut_bytes();
+//! bytes[..word].copy_from_slice(&size.to_ne_bytes());
+//! bytes[word..2 * word].copy_from_slice(&5usize.to_ne_bytes());
+//! bytes[2 * word..2 * word + 4].copy_from_slice(&42u32.to_ne_bytes());
+//! bytes[size..size + 5].copy_from_slice(b"Alice");
+//!
+//! let record = decode::<Record>(&mut bytes[..size + 5])?;
+//! assert_eq!(record.name, "Alice");
+//! assert_eq!(record.age, 42);
+//! # Ok(())
+//! # }
+//! ```
+
+pub use {Error, ErrorKind, Exhume, Heap, Validate, decode};
@@ -0,0 +1,64 @@
+use Exhume;
+use core::ptr;
+use core::str;
+use dst::{self, ExhumeUnsized};
+use error::Error;
+use heap::Heap;
+
+/// A UTF-8 string stored as its length, a little-endian `u32`, immediately
+/// followed by its bytes, rather than behind an offset.
+///
+/// It is unsized like the types implementing `ExhumeUnsized`, so that a
+/// `&PString` spans its bytes, and is exhumed from an offset to its length.
+/// The string still holds no offset of its own: the one exhumed is that of
+/// the reference, as for any other pointee. A sized `PString` would have
+/// been the 4 bytes of its length alone, which neither fits inline in a
+/// type whose size is fixed nor lets a `&PString` reach the bytes after it
+/// without reading past its referent.
+#[repr(C)]
+pub struct PString {
+    len: [u8; 4],
+    bytes: [u8],
+}
+
+impl PString {
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.bytes) }
+    }
+}
+
+unsafe impl<'input> ExhumeUnsized<'input> for PString {
+    type Header = [u8; 4];
+    type Element = u8;
+
+    unsafe fn tail_len(header: *const [u8; 4], _heap: &Heap) -> usize {
+        u32::from_le_bytes(*header) as usize
+    }
+
+    fn from_raw_parts(data: *mut u8, len: usize) -> *mut Self {
+        ptr::slice_from_raw_parts_mut(data, len) as *mut Self
+    }
+}
+
+impl<'input> Exhume<'input> for &'input PString {
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        dst::exhume_unsized(this, heap)?;
+        match str::from_utf8(&(*this).bytes) {
+            Ok(_) => Ok(()),
+            Err(_) => heap.invalid(),
+        }
+    }
+}
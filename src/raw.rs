@@ -0,0 +1,69 @@
+use Exhume;
+use core::mem;
+use core::ptr::NonNull;
+use core::slice;
+use error::{self, Error, PointerKind};
+use heap::Heap;
+
+/// A slice stored as a `#[repr(C)]` pair of a non-null data pointer and a
+/// length, for formats which cannot rely on the layout of `&[T]`. Like
+/// `&[T]`, `Option<RawSlice<T>>` uses the null pointer for `None`.
+#[repr(C)]
+pub struct RawSlice<T> {
+    ptr: NonNull<T>,
+    len: usize,
+}
+
+impl<T> RawSlice<T> {
+    /// Fails to compile unless `Option<RawSlice<T>>` has the layout of
+    /// `RawSlice<T>`, which its impl of `Exhume` relies on.
+    const HAS_NICHE: () = assert!(
+        mem::size_of::<Option<RawSlice<T>>>() == mem::size_of::<RawSlice<T>>()
+    );
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<'input, T> Exhume<'input> for RawSlice<T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let offset = heap.native_word(this as *mut usize);
+        if heap.is_null(offset) {
+            return Err(error::null_pointer(PointerKind::Slice));
+        }
+        let len = heap.native_word(&mut (*this).len as *mut usize);
+        let ptr = heap.reserve::<T>(offset, len)?;
+        heap.descend(|heap| T::exhume_slice(ptr, len, heap))?;
+        heap.exhumed(ptr, len);
+        (*this).ptr = NonNull::new_unchecked(ptr);
+        Ok(())
+    }
+}
+
+impl<'input, T> Exhume<'input> for Option<RawSlice<T>>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = false;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        #[allow(clippy::let_unit_value)]
+        let () = RawSlice::<T>::HAS_NICHE;
+        if heap.take_null(this as *mut usize) {
+            return Ok(());
+        }
+        RawSlice::exhume(this as *mut RawSlice<T>, heap)
+    }
+}
@@ -0,0 +1,86 @@
+use Exhume;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::slice;
+use error::Error;
+use heap::Heap;
+
+/// A self-relative pointer, storing the offset of its pointee from its own
+/// address rather than from the start of the input.
+///
+/// Unlike `&T`, it is left as is once exhumed and resolved on every deref,
+/// so the buffer stays valid when moved or concatenated with others.
+#[repr(transparent)]
+pub struct RelPtr<T> {
+    offset: isize,
+    marker: PhantomData<T>,
+}
+
+impl<'input, T> Exhume<'input> for RelPtr<T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let offset = heap.native_word(&mut (*this).offset as *mut isize);
+        let ptr = heap.reserve_relative::<T>(this as *const u8, offset, 1)?;
+        heap.descend(|heap| T::exhume(ptr, heap))?;
+        heap.exhumed(ptr, 1);
+        Ok(())
+    }
+}
+
+impl<T> Deref for RelPtr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe {
+            let base = self as *const Self as *const u8;
+            &*(base.offset(self.offset) as *const T)
+        }
+    }
+}
+
+/// A self-relative slice pointer, the `&[T]` counterpart of `RelPtr`.
+#[repr(C)]
+pub struct RelSlice<T> {
+    offset: isize,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'input, T> Exhume<'input> for RelSlice<T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let offset = heap.native_word(&mut (*this).offset as *mut isize);
+        let len = heap.native_word(&mut (*this).len as *mut usize);
+        let ptr =
+            heap.reserve_relative::<T>(this as *const u8, offset, len)?;
+        heap.descend(|heap| T::exhume_slice(ptr, len, heap))?;
+        heap.exhumed(ptr, len);
+        Ok(())
+    }
+}
+
+impl<T> Deref for RelSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe {
+            let base = self as *const Self as *const u8;
+            let ptr = base.offset(self.offset) as *const T;
+            slice::from_raw_parts(ptr, self.len)
+        }
+    }
+}
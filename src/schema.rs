@@ -0,0 +1,50 @@
+use Exhume;
+use error::{self, Error};
+use heap::decode_after_header;
+
+/// A fingerprint of the layout of a type, stored before the values of that
+/// type by `decode_typed` so that a buffer is not decoded as another type.
+///
+/// There is no derive for it, so it is implemented by hand, usually by
+/// hashing a description of the fields with `schema_hash`. The description
+/// must be updated whenever the fields are.
+///
+/// ```ignore
+/// impl Schema for Point {
+///     const SCHEMA_HASH: u64 = schema_hash("Point { x: i32, y: i32 }");
+/// }
+/// ```
+pub trait Schema {
+    const SCHEMA_HASH: u64;
+}
+
+/// Hashes `description` with 64-bit FNV-1a, which is stable across builds
+/// and platforms.
+pub const fn schema_hash(description: &str) -> u64 {
+    let bytes = description.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Decodes a `T` like `decode_after_header`, from an input starting with
+/// `T::SCHEMA_HASH` as a `u64` in the byte order of the host, failing with
+/// `SchemaMismatch` if it was written for another type.
+pub fn decode_typed<'input, T>(
+    input: &'input mut [u8],
+) -> Result<&'input T, Error>
+where
+    T: Exhume<'input> + Schema,
+{
+    let mut hash = [0; 8];
+    hash.copy_from_slice(input.get(..8).ok_or(error::out_of_bounds())?);
+    if u64::from_ne_bytes(hash) != T::SCHEMA_HASH {
+        return Err(error::schema_mismatch());
+    }
+    decode_after_header(input, 8)
+}
@@ -0,0 +1,76 @@
+use Exhume;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::slice;
+use error::{self, Error};
+use heap::decode;
+use std::io::Read;
+use std::sync::Arc;
+
+/// A decoded value sharing ownership of its buffer.
+///
+/// The buffer is relocated once in `new`, and only written to afterwards
+/// through whatever interior mutability `T` has, such as atomics, which is
+/// why its bytes are `UnsafeCell`s. Handing out `&T` from any number of
+/// clones is thus only as thread-safe as `&T` itself: the wrapper is `Send`
+/// and `Sync` whenever `T` is `Sync`.
+///
+/// The root type must be exhumable for any lifetime, as a `T` borrowing
+/// from the buffer would let that borrow outlive the last clone.
+pub struct SharedDecoded<T> {
+    buffer: Arc<[UnsafeCell<u8>]>,
+    marker: PhantomData<*const T>,
+}
+
+unsafe impl<T> Send for SharedDecoded<T> where T: Sync {}
+unsafe impl<T> Sync for SharedDecoded<T> where T: Sync {}
+
+impl<T> SharedDecoded<T>
+where
+    T: for<'input> Exhume<'input>,
+{
+    pub fn new(bytes: Vec<u8>) -> Result<Self, Error> {
+        let mut buffer = bytes
+            .into_iter()
+            .map(UnsafeCell::new)
+            .collect::<Arc<[_]>>();
+        let cells = Arc::get_mut(&mut buffer).unwrap();
+        let len = cells.len();
+        let ptr = cells.as_mut_ptr() as *mut u8;
+        decode::<T>(unsafe { slice::from_raw_parts_mut(ptr, len) })?;
+        Ok(SharedDecoded { buffer, marker: PhantomData })
+    }
+}
+
+/// Reads exactly `len` bytes from `reader` and decodes a `T` from them,
+/// failing with `ErrorKind::Io(UnexpectedEof)` if the reader ends first.
+pub fn decode_frame<T, R>(
+    mut reader: R,
+    len: usize,
+) -> Result<SharedDecoded<T>, Error>
+where
+    T: for<'input> Exhume<'input>,
+    R: Read,
+{
+    let mut bytes = vec![0; len];
+    reader.read_exact(&mut bytes).map_err(error::io)?;
+    SharedDecoded::new(bytes)
+}
+
+impl<T> Clone for SharedDecoded<T> {
+    fn clone(&self) -> Self {
+        SharedDecoded {
+            buffer: self.buffer.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Deref for SharedDecoded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*(self.buffer.as_ptr() as *const T) }
+    }
+}
@@ -0,0 +1,41 @@
+use Exhume;
+use core::mem::MaybeUninit;
+use error::Error;
+use heap::Heap;
+
+/// An optional value laid out like a C tagged union: a `u32` tag, 0 for
+/// none and 1 for some, followed by the payload at the next offset
+/// suitably aligned for `T`, whose bytes are ignored when the tag is 0.
+#[repr(C)]
+pub struct CTaggedOption<T> {
+    tag: u32,
+    payload: MaybeUninit<T>,
+}
+
+impl<T> CTaggedOption<T> {
+    pub fn get(&self) -> Option<&T> {
+        if self.tag == 1 {
+            Some(unsafe { self.payload.assume_init_ref() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'input, T> Exhume<'input> for CTaggedOption<T>
+where
+    T: Exhume<'input>,
+{
+    const IS_RELOCATION_FREE: bool = T::IS_RELOCATION_FREE;
+
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        match heap.native(&mut (*this).tag as *mut u32) {
+            0 => Ok(()),
+            1 => T::exhume((*this).payload.as_mut_ptr(), heap),
+            _ => heap.invalid(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use core::any::TypeId;
+use core::marker::PhantomData;
+use core::mem;
+
+/// Returns the `TypeId` of `T` with its lifetimes erased, which unlike
+/// `TypeId::of` does not require `T: 'static`, so that types borrowing the
+/// input have one too.
+///
+/// Types only differing by their lifetimes share it, which is fine for
+/// values exhumed from the same input, whose lifetimes are all `'input`.
+pub fn of<T: ?Sized>() -> TypeId {
+    trait NonStaticAny {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let marker: &dyn NonStaticAny = &PhantomData::<T>;
+    let marker = unsafe {
+        mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(
+            marker,
+        )
+    };
+    marker.type_id()
+}
@@ -0,0 +1,33 @@
+/// Implements `Exhume` for an uninhabited type, such as an enum without
+/// variants, which no bytes represent and which thus always fails.
+///
+/// This is an error even when `decode_collect_errors` is collecting them,
+/// as carrying on would produce a value which cannot exist.
+///
+/// ```ignore
+/// enum Never {}
+///
+/// uninhabited_exhume!(Never);
+/// ```
+#[macro_export]
+macro_rules! uninhabited_exhume {
+    ($($ty:ty),+) => {
+        uninhabited_exhume!($($ty,)+);
+    };
+    ($($ty:ty,)+) => {
+        $(impl<'input> $crate::Exhume<'input> for $ty {
+            const IS_RELOCATION_FREE: bool = true;
+
+            unsafe fn exhume(
+                _this: *mut Self,
+                _heap: &mut $crate::Heap<'input>,
+            ) -> $crate::__Result<(), $crate::Error> {
+                #[allow(dead_code)]
+                fn assert_shape(value: $ty) {
+                    match value {}
+                }
+                Err($crate::Error::invalid())
+            }
+        })+
+    };
+}
@@ -0,0 +1,35 @@
+//! Meant to also run under Miri, with `cargo +nightly miri test --test
+//! aliasing`, to check that writing relocated pointers back does not
+//! invalidate the pointees they are adjacent to.
+
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::decode;
+
+#[test]
+fn relocates_references_to_adjacent_pointees() {
+    let mut input = Input::new(WORD + 4).word(0, WORD).u32(WORD, 42);
+    assert_eq!(**decode::<&u32>(input.as_mut_bytes()).unwrap(), 42);
+}
+
+#[test]
+fn relocates_slices_of_adjacent_elements() {
+    let mut input = Input::new(2 * WORD + 8)
+        .word(0, 2 * WORD)
+        .word(WORD, 2)
+        .u32(2 * WORD, 1)
+        .u32(2 * WORD + 4, 2);
+    assert_eq!(*decode::<&[u32]>(input.as_mut_bytes()).unwrap(), &[1, 2]);
+}
+
+#[test]
+fn relocates_chains_of_adjacent_references() {
+    let mut input = Input::new(2 * WORD + 4)
+        .word(0, WORD)
+        .word(WORD, 2 * WORD)
+        .u32(2 * WORD, 42);
+    assert_eq!(***decode::<&&u32>(input.as_mut_bytes()).unwrap(), 42);
+}
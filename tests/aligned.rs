@@ -0,0 +1,61 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{
+    AlignedBytes,
+    Buffer,
+    Error,
+    ErrorKind,
+    Exhume,
+    Heap,
+    decode_aligned,
+    decode_buffer,
+};
+
+#[repr(C)]
+struct Header {
+    magic: u64,
+    len: u32,
+}
+
+impl<'input> Exhume<'input> for Header {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u64::exhume(&mut (*this).magic, heap)?;
+        u32::exhume(&mut (*this).len, heap)
+    }
+}
+
+#[test]
+fn decodes_from_aligned_bytes() {
+    let mut bytes = [0; 16];
+    bytes[..8].copy_from_slice(&0x1234u64.to_ne_bytes());
+    bytes[8..12].copy_from_slice(&3u32.to_ne_bytes());
+    let mut input = AlignedBytes::<Header, 16>::new(bytes);
+    assert_eq!(input.as_bytes().as_ptr() as usize % 8, 0);
+    let header = decode_aligned(&mut input).unwrap();
+    assert_eq!((header.magic, header.len), (0x1234, 3));
+}
+
+#[test]
+fn rejects_misaligned_buffers() {
+    let mut input = Input::new(16);
+    let error = Buffer::aligned_to::<u64>(&mut input.as_mut_bytes()[4..])
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Misaligned);
+}
+
+#[test]
+fn decodes_roots_needing_at_most_the_checked_alignment() {
+    let mut input = Input::new(8).u32(0, 42);
+    let buffer = Buffer::aligned_to::<u32>(input.as_mut_bytes()).unwrap();
+    assert_eq!(decode_buffer::<u32>(buffer), Ok(&42));
+    let buffer = Buffer::aligned_to::<u32>(input.as_mut_bytes()).unwrap();
+    let error = decode_buffer::<u64>(buffer).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Misaligned);
+}
@@ -0,0 +1,93 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD, bytes_of};
+use ignominie::{ErrorKind, decode};
+use std::num::FpCategory;
+
+#[test]
+fn decodes_matrices() {
+    let mut matrix = [[0f32; 16]; 16];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (16 * i + j) as f32;
+        }
+    }
+    let expected = matrix;
+    assert_eq!(decode::<[[f32; 16]; 16]>(bytes_of(&mut matrix)), Ok(&expected));
+}
+
+#[test]
+fn rejects_matrices_with_a_signaling_nan() {
+    let mut matrix = [[1f32; 16]; 16];
+    matrix[7][9] = f32::from_bits(0x7FA00000);
+    let error = decode::<[[f32; 16]; 16]>(bytes_of(&mut matrix)).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn relocates_arrays_of_references() {
+    let offsets = [4 * WORD, 4 * WORD + 8, 4 * WORD + 12, 4 * WORD + 20];
+    let mut input = Input::new(8 * WORD);
+    for (i, &offset) in offsets.iter().enumerate() {
+        input = input.word(i * WORD, offset).u32(offset, 10 * i as u32 + 1);
+    }
+    let bytes = input.as_mut_bytes();
+    let start = bytes.as_ptr() as usize;
+    let refs = decode::<[&u32; 4]>(bytes).unwrap();
+    for (i, &offset) in offsets.iter().enumerate() {
+        assert_eq!(*refs[i], 10 * i as u32 + 1);
+        assert_eq!(refs[i] as *const u32 as usize, start + offset);
+    }
+}
+
+#[test]
+fn rejects_arrays_with_an_invalid_enum() {
+    let mut bytes = [
+        FpCategory::Nan as u8,
+        FpCategory::Zero as u8,
+        FpCategory::Normal as u8,
+        5,
+        FpCategory::Infinite as u8,
+    ];
+    let error = decode::<[FpCategory; 5]>(&mut bytes).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(error.index(), Some(3));
+    bytes[3] = FpCategory::Subnormal as u8;
+    assert!(decode::<[FpCategory; 5]>(&mut bytes).is_ok());
+}
+
+/// Returns an input holding a `[[[&str; 2]; 2]; 2]` whose leaves are
+/// the single bytes `leaves`, right after the array.
+fn cube(leaves: [u8; 8]) -> Input {
+    let mut input = Input::new(16 * WORD + 8).bytes(16 * WORD, &leaves);
+    for i in 0..8 {
+        input = input
+            .word(2 * i * WORD, 16 * WORD + i)
+            .word((2 * i + 1) * WORD, 1);
+    }
+    input
+}
+
+#[test]
+fn relocates_every_leaf_of_nested_arrays() {
+    let mut input = cube(*b"abcdefgh");
+    let bytes = input.as_mut_bytes();
+    let start = bytes.as_ptr() as usize;
+    let cube = decode::<[[[&str; 2]; 2]; 2]>(bytes).unwrap();
+    for i in 0..8 {
+        let leaf = cube[i / 4][i / 2 % 2][i % 2];
+        assert_eq!(leaf.as_bytes(), &[b'a' + i as u8]);
+        assert_eq!(leaf.as_ptr() as usize, start + 16 * WORD + i);
+    }
+}
+
+#[test]
+fn rejects_nested_arrays_with_a_bad_leaf() {
+    let mut input = cube(*b"abcdef\xFFh");
+    let error = decode::<[[[&str; 2]; 2]; 2]>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
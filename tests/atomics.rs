@@ -0,0 +1,36 @@
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use ignominie::{ErrorKind, decode};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+#[test]
+fn validates_atomic_bools_like_bools() {
+    for byte in 0..2 {
+        let mut bytes = [byte];
+        let value = decode::<AtomicBool>(&mut bytes).unwrap();
+        assert_eq!(value.load(Ordering::Relaxed), byte == 1);
+    }
+    let error = decode::<AtomicBool>(&mut [2]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn decodes_atomic_integers() {
+    let mut value = 42u32;
+    let atomic = decode::<AtomicU32>(bytes_of(&mut value)).unwrap();
+    assert_eq!(atomic.fetch_add(1, Ordering::Relaxed), 42);
+    assert_eq!(value, 43);
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn decodes_64_bit_atomics_where_the_target_has_them() {
+    use std::sync::atomic::AtomicU64;
+
+    let mut value = 42u64;
+    let atomic = decode::<AtomicU64>(bytes_of(&mut value)).unwrap();
+    assert_eq!(atomic.load(Ordering::Relaxed), 42);
+}
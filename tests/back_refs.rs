@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{
+    BackRef,
+    Error,
+    ErrorKind,
+    Exhume,
+    Heap,
+    Pair,
+    decode_with_back_refs,
+};
+
+/// A `&[u32]` whose elements are reserved through `Heap::region` but never
+/// exhumed.
+struct Unexhumed([usize; 2]);
+
+impl<'input> Exhume<'input> for Unexhumed {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let [offset, len] = (*this).0;
+        heap.region::<u32, _, _>(offset, len, |_| Ok(()))
+    }
+}
+
+/// Returns an input holding a pair of a slice of two `u32`s and a back
+/// reference to `offset` from the start of that slice.
+fn input(offset: usize) -> Input {
+    Input::new(4 * WORD)
+        .word(0, 3 * WORD)
+        .word(WORD, 2)
+        .word(2 * WORD, 3 * WORD + offset)
+        .u32(3 * WORD, 7)
+        .u32(3 * WORD + 4, 9)
+}
+
+#[test]
+fn points_to_exhumed_values() {
+    let mut input = input(4);
+    let root = decode_with_back_refs::<Pair<&[u32], BackRef<u32>>>(
+        input.as_mut_bytes(),
+    )
+    .unwrap();
+    assert_eq!(*root.1, 9);
+    assert!(std::ptr::eq(&*root.1, &root.0[1]));
+}
+
+#[test]
+fn rejects_values_of_another_type() {
+    let mut input = input(4);
+    let error = decode_with_back_refs::<Pair<&[u32], BackRef<i32>>>(
+        input.as_mut_bytes(),
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn rejects_values_reserved_but_not_exhumed() {
+    let mut input = input(4);
+    let error = decode_with_back_refs::<Pair<Unexhumed, BackRef<u32>>>(
+        input.as_mut_bytes(),
+    )
+    .map(|_| ())
+    .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,44 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{Bitmap, Error, Exhume, Heap, Optional, decode};
+use std::mem;
+
+#[repr(C)]
+struct Record<'input> {
+    presence: Bitmap<1>,
+    name: Optional<&'input str>,
+    age: Optional<u32>,
+}
+
+impl<'input> Exhume<'input> for Record<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let presence = &(*this).presence;
+        presence.exhume_field(0, &mut (*this).name, heap)?;
+        presence.exhume_field(1, &mut (*this).age, heap)
+    }
+}
+
+#[test]
+fn skips_absent_fields() {
+    let mut input = Input::new(mem::size_of::<Record>());
+    let bytes = input.as_mut_bytes();
+    bytes[0] = 0b10;
+    // Garbage which would be an out of bounds `&str` if it were exhumed.
+    let name = mem::offset_of!(Record, name);
+    for byte in &mut bytes[name..name + mem::size_of::<&str>()] {
+        *byte = 0xff;
+    }
+    let age = mem::offset_of!(Record, age);
+    bytes[age..age + 4].copy_from_slice(&42u32.to_ne_bytes());
+    let record = decode::<Record>(bytes).unwrap();
+    unsafe {
+        assert!(record.presence.get(0, &record.name).is_none());
+        assert_eq!(record.presence.get(1, &record.age), Some(&42));
+    }
+}
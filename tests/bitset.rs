@@ -0,0 +1,39 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{BitSet, decode};
+
+/// Returns an input holding a `BitSet` of bits 0, 5 and 63 of its first
+/// word and bit 1 of its second, i.e. of 0, 5, 63 and 65.
+fn input() -> Input {
+    Input::new(2 * WORD + 16)
+        .word(0, 2 * WORD)
+        .word(WORD, 2)
+        .bytes(2 * WORD, &(1u64 | 1 << 5 | 1 << 63).to_ne_bytes())
+        .bytes(2 * WORD + 8, &2u64.to_ne_bytes())
+}
+
+#[test]
+fn contains_its_bits() {
+    let mut input = input();
+    let set = decode::<BitSet>(input.as_mut_bytes()).unwrap();
+    let ones = (0..128).filter(|&i| set.contains(i)).collect::<Vec<_>>();
+    assert_eq!(ones, [0, 5, 63, 65]);
+}
+
+#[test]
+fn iterates_over_its_bits() {
+    let mut input = input();
+    let set = decode::<BitSet>(input.as_mut_bytes()).unwrap();
+    assert_eq!(set.iter_ones().collect::<Vec<_>>(), [0, 5, 63, 65]);
+}
+
+#[test]
+fn does_not_contain_indices_past_its_words() {
+    let mut input = input();
+    let set = decode::<BitSet>(input.as_mut_bytes()).unwrap();
+    assert!(!set.contains(128));
+    assert!(!set.contains(usize::MAX));
+}
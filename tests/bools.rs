@@ -0,0 +1,33 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, decode};
+
+/// Returns an input holding a `&[bool]` of `bytes`.
+fn input(bytes: &[u8]) -> Input {
+    Input::new(2 * WORD + bytes.len())
+        .word(0, 2 * WORD)
+        .word(WORD, bytes.len())
+        .bytes(2 * WORD, bytes)
+}
+
+#[test]
+fn accepts_long_slices() {
+    let bytes = (0..200).map(|i| (i % 3 == 0) as u8).collect::<Vec<_>>();
+    let mut input = input(&bytes);
+    let bools = decode::<&[bool]>(input.as_mut_bytes()).unwrap();
+    assert_eq!(bools.len(), 200);
+    assert!(bools.iter().enumerate().all(|(i, &b)| b == (i % 3 == 0)));
+}
+
+#[test]
+fn rejects_a_single_bad_byte() {
+    let mut bytes = [1; 200];
+    bytes[130] = 2;
+    let mut input = input(&bytes);
+    let error = decode::<&[bool]>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(error.index(), Some(130));
+}
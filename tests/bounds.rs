@@ -0,0 +1,54 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD, bytes_of};
+use ignominie::{Error, ErrorKind, decode};
+use std::mem;
+
+/// Decodes a `&[u32]` whose stored offset and length are given by `f`,
+/// from the address of the input.
+fn decode_slice<F>(f: F) -> Result<(), Error>
+where
+    F: FnOnce(usize) -> (usize, usize),
+{
+    let mut words = [0usize; 4];
+    let (offset, len) = f(words.as_ptr() as usize);
+    words[0] = offset;
+    words[1] = len;
+    decode::<&[u32]>(bytes_of(&mut words)).map(|_| ())
+}
+
+#[test]
+fn rejects_maximum_offset() {
+    let error = decode_slice(|_| (usize::MAX, 1)).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn rejects_overflowing_length() {
+    let len = usize::MAX / mem::size_of::<u32>() + 1;
+    let error = decode_slice(|_| (2 * WORD, len)).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn rejects_wrapping_end() {
+    // The start of the slice is the last aligned address, so its end
+    // wraps around.
+    let error = decode_slice(|start| ((usize::MAX - start) & !3, 4))
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn rejects_byte_slices_running_past_the_input() {
+    let input = |len| {
+        Input::new(2 * WORD + 4).word(0, 2 * WORD).word(WORD, len)
+    };
+    let mut fitting = input(4);
+    assert_eq!(decode::<&[u8]>(fitting.as_mut_bytes()).unwrap().len(), 4);
+    let mut past = input(5);
+    let error = decode::<&[u8]>(past.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{BoxedSlice, decode};
+
+const LEN: usize = 1 << 20;
+
+#[test]
+fn copies_large_slices() {
+    let mut input = Input::new(16 + 4 * LEN).word(0, 16).word(WORD, LEN);
+    let bytes = input.as_mut_bytes();
+    for (i, element) in bytes[16..].chunks_mut(4).enumerate() {
+        element.copy_from_slice(&(i as u32).to_ne_bytes());
+    }
+    let root = decode::<BoxedSlice<u32>>(bytes).unwrap();
+    let expected = (0..LEN as u32).collect::<Box<[u32]>>();
+    assert_eq!(root.to_boxed(), expected);
+}
+
+#[test]
+fn outlives_changes_to_the_input() {
+    let mut input = Input::new(2 * WORD + 8)
+        .word(0, 2 * WORD)
+        .word(WORD, 2)
+        .u32(2 * WORD, 1)
+        .u32(2 * WORD + 4, 2);
+    let boxed = decode::<BoxedSlice<u32>>(input.as_mut_bytes())
+        .unwrap()
+        .to_boxed();
+    input.as_mut_bytes()[2 * WORD..].copy_from_slice(&[0xFF; 8]);
+    assert_eq!(*boxed, [1, 2]);
+}
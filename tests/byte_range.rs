@@ -0,0 +1,40 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD, bytes_of_ref};
+use ignominie::{byte_range_of, decode};
+use std::slice;
+
+#[test]
+fn locates_decoded_slices() {
+    let mut input = Input::new(2 * WORD + 12)
+        .word(0, 2 * WORD)
+        .word(WORD, 3)
+        .u32(2 * WORD, 1)
+        .u32(2 * WORD + 4, 2)
+        .u32(2 * WORD + 8, 3);
+    let bytes = input.as_mut_bytes();
+    let buffer: *const [u8] = bytes;
+    let slice = *decode::<&[u32]>(bytes).unwrap();
+    let range = byte_range_of(slice, buffer).unwrap();
+    assert_eq!(range, 2 * WORD..2 * WORD + 12);
+    let data = unsafe { &(&*buffer)[range] };
+    assert_eq!(data, bytes_of_ref(slice));
+    let tail = byte_range_of(&slice[1..], buffer);
+    assert_eq!(tail, Some(2 * WORD + 4..2 * WORD + 12));
+}
+
+#[test]
+fn rejects_slices_outside_the_buffer() {
+    let input = Input::new(16);
+    let bytes = input.as_bytes();
+    let other = [1u32, 2];
+    assert_eq!(byte_range_of(&other, bytes), None);
+    // Starts within the first half of the input, but ends past it.
+    let words = unsafe {
+        slice::from_raw_parts(bytes.as_ptr().add(4) as *const u32, 2)
+    };
+    assert_eq!(byte_range_of(words, &bytes[..8]), None);
+    assert_eq!(byte_range_of(words, bytes), Some(4..12));
+}
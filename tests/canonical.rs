@@ -0,0 +1,45 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Pair, canonical_hash, decode};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+fn hash(input: &mut Input) -> u64 {
+    let value = decode::<Pair<u8, &str>>(input.as_mut_bytes()).unwrap();
+    let mut state = DefaultHasher::new();
+    canonical_hash(value, &mut state);
+    state.finish()
+}
+
+#[test]
+fn ignores_padding_and_placement() {
+    let mut zeroed = Input::new(3 * WORD + 5)
+        .bytes(0, &[1])
+        .word(WORD, 3 * WORD)
+        .word(2 * WORD, 5)
+        .bytes(3 * WORD, b"hello");
+    let mut padded = Input::new(4 * WORD + 5)
+        .bytes(0, &[1, 0xAA, 0xBB, 0xCC])
+        .word(WORD, 4 * WORD)
+        .word(2 * WORD, 5)
+        .bytes(3 * WORD, &[0xDD; 8])
+        .bytes(4 * WORD, b"hello");
+    assert_eq!(hash(&mut zeroed), hash(&mut padded));
+}
+
+#[test]
+fn tells_different_contents_apart() {
+    let input = |byte, string: &[u8]| {
+        Input::new(3 * WORD + 5)
+            .bytes(0, &[byte])
+            .word(WORD, 3 * WORD)
+            .word(2 * WORD, 5)
+            .bytes(3 * WORD, string)
+    };
+    let hello = hash(&mut input(1, b"hello"));
+    assert_ne!(hello, hash(&mut input(2, b"hello")));
+    assert_ne!(hello, hash(&mut input(1, b"world")));
+}
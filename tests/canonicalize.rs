@@ -0,0 +1,34 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Pair, decode_canonicalize};
+
+/// Returns an input holding a `Pair<&u32, &[u8]>`, with `gap` between the
+/// root and the `u32`, and after the bytes.
+fn input(gap: u8) -> Input {
+    Input::new(3 * WORD + 16)
+        .word(0, 3 * WORD + 4)
+        .word(WORD, 3 * WORD + 8)
+        .word(2 * WORD, 3)
+        .bytes(3 * WORD, &[gap; 4])
+        .u32(3 * WORD + 4, 42)
+        .bytes(3 * WORD + 8, b"abc")
+        .bytes(3 * WORD + 11, &[gap; 5])
+}
+
+#[test]
+fn zeroes_bytes_between_and_after_values() {
+    let mut inputs = [input(0xAA), input(0x55)];
+    for input in &mut inputs {
+        let bytes = input.as_mut_bytes();
+        let root = decode_canonicalize::<Pair<&u32, &[u8]>>(bytes).unwrap();
+        assert_eq!((*root.0, root.1), (42, &b"abc"[..]));
+    }
+    // The root holds relocated pointers, which differ between inputs.
+    let [first, second] = inputs;
+    assert_eq!(first.as_bytes()[3 * WORD..], second.as_bytes()[3 * WORD..]);
+    assert_eq!(first.as_bytes()[3 * WORD..3 * WORD + 4], [0; 4]);
+    assert_eq!(first.as_bytes()[3 * WORD + 11..], [0; 5]);
+}
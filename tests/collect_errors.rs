@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, Pair, decode_collect_errors};
+
+/// Returns an input holding a pair of `&str`s pointing to `first` and
+/// `second` after it.
+fn input(first: &[u8; 2], second: &[u8; 2]) -> Input {
+    Input::new(4 * WORD + 4)
+        .word(0, 4 * WORD)
+        .word(WORD, 2)
+        .word(2 * WORD, 4 * WORD + 2)
+        .word(3 * WORD, 2)
+        .bytes(4 * WORD, first)
+        .bytes(4 * WORD + 2, second)
+}
+
+#[test]
+fn reports_every_invalid_string() {
+    let mut input = input(b"\xC3\x28", b"\xFF\x61");
+    let errors = decode_collect_errors::<Pair<&str, &str>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    let kinds = errors.iter().map(|error| error.kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, [ErrorKind::Invalid, ErrorKind::Invalid]);
+}
+
+#[test]
+fn returns_valid_values() {
+    let mut input = input(b"ab", b"cd");
+    let root = decode_collect_errors::<Pair<&str, &str>>(input.as_mut_bytes())
+        .unwrap();
+    assert_eq!((root.0, root.1), ("ab", "cd"));
+}
+
+#[test]
+fn stops_at_layout_errors() {
+    let mut input = input(b"\xC3\x28", b"cd").word(3 * WORD, 3);
+    let errors = decode_collect_errors::<Pair<&str, &str>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    let kinds = errors.iter().map(|error| error.kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, [ErrorKind::Invalid, ErrorKind::OutOfBounds]);
+}
+
+#[test]
+fn reports_the_index_of_every_bad_element() {
+    let mut input = Input::new(2 * WORD + 5)
+        .word(0, 2 * WORD)
+        .word(WORD, 5)
+        .bytes(2 * WORD, &[0, 2, 1, 3, 1]);
+    let errors = decode_collect_errors::<&[bool]>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    let indices = errors.iter().map(|error| error.index()).collect::<Vec<_>>();
+    assert_eq!(indices, [Some(1), Some(3)]);
+}
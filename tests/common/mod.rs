@@ -0,0 +1,58 @@
+//! Helpers shared by the integration tests to lay out inputs.
+
+#![allow(dead_code)]
+
+use std::mem;
+use std::slice;
+
+/// The size of the offsets and lengths of pointers.
+pub const WORD: usize = mem::size_of::<usize>();
+
+/// Returns the bytes of `value`, usually an array of words so that they are
+/// aligned for any root.
+pub fn bytes_of<T: ?Sized>(value: &mut T) -> &mut [u8] {
+    let len = mem::size_of_val(value);
+    unsafe { slice::from_raw_parts_mut(value as *mut T as *mut u8, len) }
+}
+
+/// Returns the bytes of `value` like `bytes_of`, but shared.
+pub fn bytes_of_ref<T: ?Sized>(value: &T) -> &[u8] {
+    let len = mem::size_of_val(value);
+    unsafe { slice::from_raw_parts(value as *const T as *const u8, len) }
+}
+
+/// Zeroed bytes aligned to 8, filled in at given offsets.
+pub struct Input {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Input {
+    pub fn new(len: usize) -> Self {
+        Input { words: vec![0; len.div_ceil(8)], len }
+    }
+
+    /// Writes the offset or length of a pointer at `at`.
+    pub fn word(self, at: usize, value: usize) -> Self {
+        self.bytes(at, &value.to_ne_bytes())
+    }
+
+    /// Writes a `u32` at `at`.
+    pub fn u32(self, at: usize, value: u32) -> Self {
+        self.bytes(at, &value.to_ne_bytes())
+    }
+
+    pub fn bytes(mut self, at: usize, bytes: &[u8]) -> Self {
+        self.as_mut_bytes()[at..at + bytes.len()].copy_from_slice(bytes);
+        self
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &bytes_of_ref(&self.words[..])[..self.len]
+    }
+
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        let len = self.len;
+        &mut bytes_of(&mut self.words[..])[..len]
+    }
+}
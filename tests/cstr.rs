@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, NulKind, decode};
+use std::ffi::CStr;
+
+fn decode_cstr(bytes: &[u8]) -> Result<Vec<u8>, ErrorKind> {
+    let mut input = Input::new(2 * WORD + bytes.len())
+        .word(0, 2 * WORD)
+        .word(WORD, bytes.len())
+        .bytes(2 * WORD, bytes);
+    decode::<&CStr>(input.as_mut_bytes())
+        .map(|cstr| cstr.to_bytes().to_vec())
+        .map_err(|error| error.kind())
+}
+
+#[test]
+fn decodes_nul_terminated_strings() {
+    assert_eq!(decode_cstr(b"ab\0"), Ok(b"ab".to_vec()));
+    assert_eq!(decode_cstr(b"\0"), Ok(vec![]));
+}
+
+#[test]
+fn rejects_interior_nuls() {
+    let interior = Err(ErrorKind::Nul(NulKind::Interior));
+    assert_eq!(decode_cstr(b"a\0b\0"), interior);
+    assert_eq!(decode_cstr(b"a\0b"), interior);
+}
+
+#[test]
+fn rejects_missing_nuls() {
+    let missing = Err(ErrorKind::Nul(NulKind::Missing));
+    assert_eq!(decode_cstr(b"ab"), missing);
+    assert_eq!(decode_cstr(b""), missing);
+}
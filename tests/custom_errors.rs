@@ -0,0 +1,94 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, bytes_of};
+use ignominie::{Error, ErrorKind, Exhume, Heap, decode, decode_with};
+
+const UNKNOWN_VERSION: u32 = 7;
+const PRIVILEGED_PORT: u32 = 8;
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    UnknownVersion,
+    Corrupt(ErrorKind),
+}
+
+impl From<Error> for AppError {
+    fn from(error: Error) -> Self {
+        match error.kind() {
+            ErrorKind::Custom(UNKNOWN_VERSION) => AppError::UnknownVersion,
+            kind => AppError::Corrupt(kind),
+        }
+    }
+}
+
+#[repr(C)]
+struct Version(u32);
+
+impl<'input> Exhume<'input> for Version {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u32::exhume(&mut (*this).0, heap)?;
+        if (*this).0 > 2 {
+            return Err(Error::custom(UNKNOWN_VERSION));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn maps_custom_errors() {
+    let mut version = 2u32;
+    let decoded = decode_with::<Version, _, _>(
+        bytes_of(&mut version),
+        AppError::from,
+    );
+    assert_eq!(decoded.unwrap().0, 2);
+    let mut version = 3u32;
+    let error = decode_with::<Version, _, _>(
+        bytes_of(&mut version),
+        AppError::from,
+    );
+    assert_eq!(error.map(|_| ()), Err(AppError::UnknownVersion));
+}
+
+#[test]
+fn maps_other_errors() {
+    let mut input = Input::new(2);
+    let error =
+        decode_with::<Version, _, _>(input.as_mut_bytes(), AppError::from);
+    let kind = ErrorKind::OutOfBounds;
+    assert_eq!(error.map(|_| ()), Err(AppError::Corrupt(kind)));
+}
+
+#[repr(C)]
+struct Port(u16);
+
+impl<'input> Exhume<'input> for Port {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u16::exhume(&mut (*this).0, heap)?;
+        match (*this).0 {
+            0 => Err(Error::invalid()),
+            1..=1023 => Err(Error::new(ErrorKind::Custom(PRIVILEGED_PORT))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn builds_errors_outside_the_crate() {
+    let decode = |port: u16| {
+        let mut port = port;
+        decode::<Port>(bytes_of(&mut port)).map(|port| port.0)
+    };
+    assert_eq!(decode(8080), Ok(8080));
+    assert_eq!(decode(0).unwrap_err().kind(), ErrorKind::Invalid);
+    let kind = decode(80).unwrap_err().kind();
+    assert_eq!(kind, ErrorKind::Custom(PRIVILEGED_PORT));
+}
@@ -0,0 +1,34 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, decode_buf};
+
+/// A backing store only exposing its bytes through `AsMut`, of which only
+/// the first `filled` are part of the input.
+struct Frame {
+    input: Input,
+    filled: usize,
+}
+
+impl AsMut<[u8]> for Frame {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.input.as_mut_bytes()[..self.filled]
+    }
+}
+
+#[test]
+fn decodes_from_custom_buffers() {
+    let input = Input::new(2 * WORD).word(0, WORD).u32(WORD, 42);
+    let mut frame = Frame { input, filled: WORD + 4 };
+    assert_eq!(**decode_buf::<_, &u32>(&mut frame).unwrap(), 42);
+}
+
+#[test]
+fn decodes_only_what_the_buffer_exposes() {
+    let input = Input::new(2 * WORD).word(0, WORD).u32(WORD, 42);
+    let mut frame = Frame { input, filled: WORD + 2 };
+    let error = decode_buf::<_, &u32>(&mut frame).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+use ignominie::{ErrorKind, decode_frame};
+use std::io::{self, Cursor, Read};
+
+/// Returns a reader of a frame of two `u32`s followed by the next frame.
+fn reader() -> Cursor<Vec<u8>> {
+    let words = [1u32, 2, 3];
+    Cursor::new(words.iter().flat_map(|n| n.to_ne_bytes()).collect())
+}
+
+#[test]
+fn decodes_a_full_frame() {
+    let mut reader = reader();
+    let frame = decode_frame::<[u32; 2], _>(&mut reader, 8).unwrap();
+    assert_eq!(*frame, [1, 2]);
+}
+
+#[test]
+fn leaves_the_bytes_after_the_frame_unread() {
+    let mut reader = reader();
+    decode_frame::<[u32; 2], _>(&mut reader, 8).unwrap();
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, 3u32.to_ne_bytes());
+}
+
+#[test]
+fn fails_with_eof_on_a_truncated_frame() {
+    let mut reader = reader();
+    let result = decode_frame::<[u32; 4], _>(&mut reader, 16);
+    let error = result.map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Io(io::ErrorKind::UnexpectedEof));
+}
@@ -0,0 +1,57 @@
+extern crate ignominie;
+
+mod common;
+
+use common::WORD;
+use ignominie::{ErrorKind, decode_uninit};
+use std::mem::{self, MaybeUninit};
+use std::slice;
+
+/// Returns uninitialised bytes, aligned to 8, for a `&[u8]` root and its
+/// bytes, with the root initialised to point to `len` bytes after it.
+fn buffer(len: usize) -> [MaybeUninit<u64>; 4] {
+    let mut buffer = [MaybeUninit::uninit(); 4];
+    let bytes = bytes_of(&mut buffer);
+    write(bytes, 0, &(2 * WORD).to_ne_bytes());
+    write(bytes, WORD, &len.to_ne_bytes());
+    buffer
+}
+
+fn bytes_of(buffer: &mut [MaybeUninit<u64>; 4]) -> &mut [MaybeUninit<u8>] {
+    let len = mem::size_of_val(buffer);
+    let data = buffer.as_mut_ptr() as *mut MaybeUninit<u8>;
+    unsafe { slice::from_raw_parts_mut(data, len) }
+}
+
+fn write(bytes: &mut [MaybeUninit<u8>], at: usize, value: &[u8]) {
+    for (byte, &value) in bytes[at..].iter_mut().zip(value) {
+        *byte = MaybeUninit::new(value);
+    }
+}
+
+#[test]
+fn decodes_from_the_filled_bytes() {
+    let mut buffer = buffer(3);
+    let bytes = bytes_of(&mut buffer);
+    write(bytes, 2 * WORD, b"abc");
+    let root = unsafe { decode_uninit::<&[u8]>(bytes, 2 * WORD + 3) };
+    assert_eq!(*root.unwrap(), b"abc");
+}
+
+#[test]
+fn fails_on_a_field_needing_bytes_past_the_filled_ones() {
+    let mut buffer = buffer(4);
+    let bytes = bytes_of(&mut buffer);
+    write(bytes, 2 * WORD, b"abc");
+    let root = unsafe { decode_uninit::<&[u8]>(bytes, 2 * WORD + 3) };
+    assert_eq!(root.unwrap_err().kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn fails_if_more_bytes_are_filled_than_there_are() {
+    let mut buffer = buffer(0);
+    let bytes = bytes_of(&mut buffer);
+    let len = bytes.len();
+    let root = unsafe { decode_uninit::<&[u8]>(bytes, len + 1) };
+    assert_eq!(root.unwrap_err().kind(), ErrorKind::OutOfBounds);
+}
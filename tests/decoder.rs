@@ -0,0 +1,40 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Decoder, ErrorKind, RawSlice};
+
+#[test]
+fn decodes_records_fed_one_byte_at_a_time() {
+    let input = Input::new(2 * WORD + 8)
+        .word(0, 2 * WORD)
+        .word(WORD, 2)
+        .u32(2 * WORD, 7)
+        .u32(2 * WORD + 4, 9);
+    let (last, bytes) = input.as_bytes().split_last().unwrap();
+    let mut decoder = Decoder::<RawSlice<u32>>::new();
+    for byte in bytes {
+        assert!(decoder.feed(&[*byte]).unwrap().is_none());
+    }
+    let slice = decoder.feed(&[*last]).unwrap().unwrap();
+    assert_eq!(slice.as_slice(), [7, 9]);
+}
+
+#[test]
+fn rejects_overflowing_lengths_at_once() {
+    let input = Input::new(2 * WORD).word(0, 2 * WORD).word(WORD, usize::MAX);
+    let mut decoder = Decoder::<RawSlice<u8>>::new();
+    let error = decoder.feed(input.as_bytes()).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn rejects_invalid_values_at_once() {
+    let mut decoder = Decoder::<[bool; 2]>::new();
+    assert_eq!(decoder.feed(&[1]), Ok(None));
+    let error = decoder.feed(&[2]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
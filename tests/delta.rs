@@ -0,0 +1,19 @@
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use ignominie::{DeltaPtr, DeltaSlice, Pair, decode};
+
+#[test]
+fn relocates_a_chain_of_deltas() {
+    // The slice comes right after the root, and the `usize` right after
+    // the slice, so both deltas are zero.
+    let mut words = [0usize, 2, 0, 7, 9, 42];
+    let root = decode::<Pair<DeltaSlice<usize>, DeltaPtr<usize>>>(
+        bytes_of(&mut words),
+    )
+    .unwrap();
+    assert_eq!(*root.0, [7, 9]);
+    assert_eq!(*root.1, 42);
+}
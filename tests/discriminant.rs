@@ -0,0 +1,24 @@
+extern crate ignominie;
+
+use ignominie::{DiscriminantTag, ErrorKind, decode};
+use std::cmp::Ordering;
+use std::mem;
+
+#[test]
+fn round_trips_tags() {
+    for ordering in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+        let mut bytes = [ordering as i8 as u8];
+        let tag = decode::<DiscriminantTag<Ordering>>(&mut bytes).unwrap();
+        assert_eq!(*tag.get(), ordering);
+        assert_eq!(tag.discriminant(), mem::discriminant(&ordering));
+    }
+}
+
+#[test]
+fn rejects_unknown_tags() {
+    let mut bytes = [2];
+    let error = decode::<DiscriminantTag<Ordering>>(&mut bytes)
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
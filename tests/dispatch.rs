@@ -0,0 +1,46 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Dispatch, ErrorKind, ExhumeFn, TaggedRef, decode};
+
+enum Numbers {}
+
+unsafe impl<'input> Dispatch<'input> for Numbers {
+    fn dispatch(tag: u32) -> Option<ExhumeFn<'input>> {
+        match tag {
+            0 => Some(ExhumeFn::of::<u32>()),
+            1 => Some(ExhumeFn::of::<i32>()),
+            2 => Some(ExhumeFn::of::<&'input str>()),
+            _ => None,
+        }
+    }
+}
+
+/// Returns an input holding a `TaggedRef` with `tag`, pointing to `value`
+/// right after it.
+fn input(tag: u32, value: u32) -> Input {
+    Input::new(3 * WORD)
+        .u32(0, tag)
+        .word(WORD, 2 * WORD)
+        .u32(2 * WORD, value)
+}
+
+#[test]
+fn gets_the_pointee_of_its_tag_type_only() {
+    let mut input = input(1, 42);
+    let tagged = decode::<TaggedRef<Numbers>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(tagged.get::<i32>(), Some(&42));
+    assert_eq!(tagged.get::<u32>(), None);
+    assert_eq!(tagged.get::<&str>(), None);
+}
+
+#[test]
+fn rejects_unknown_tags() {
+    let mut input = input(3, 42);
+    let error = decode::<TaggedRef<Numbers>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,40 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, decode};
+
+#[test]
+fn reports_the_index_of_a_bad_bool() {
+    let bytes = [0, 1, 1, 7, 0];
+    let mut input = Input::new(2 * WORD + bytes.len())
+        .word(0, 2 * WORD)
+        .word(WORD, bytes.len())
+        .bytes(2 * WORD, &bytes);
+    let error = decode::<&[bool]>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(error.index(), Some(3));
+}
+
+#[test]
+fn reports_the_index_in_the_innermost_slice() {
+    let mut input = Input::new(6 * WORD + 4)
+        .word(0, 2 * WORD)
+        .word(WORD, 2)
+        .word(2 * WORD, 6 * WORD)
+        .word(3 * WORD, 2)
+        .word(4 * WORD, 6 * WORD + 2)
+        .word(5 * WORD, 2)
+        .bytes(6 * WORD, &[1, 0, 0, 2]);
+    let error = decode::<&[&[bool]]>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(error.index(), Some(1));
+}
+
+#[test]
+fn reports_no_index_outside_of_slices() {
+    let mut input = Input::new(1).bytes(0, &[2]);
+    let error = decode::<bool>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.index(), None);
+}
@@ -0,0 +1,55 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Be, Endianness, Le, decode, decode_be, decode_header_endian};
+
+#[test]
+fn converts_big_endian_fields() {
+    let mut input = Input::new(4).bytes(0, &[0x00, 0x00, 0x00, 0x2A]);
+    assert_eq!(decode::<Be<u32>>(input.as_mut_bytes()).unwrap().get(), 42);
+}
+
+#[test]
+fn converts_little_endian_fields() {
+    let mut input = Input::new(4).bytes(0, &[0x2A, 0x00, 0x00, 0x00]);
+    assert_eq!(decode::<Le<u32>>(input.as_mut_bytes()).unwrap().get(), 42);
+}
+
+#[test]
+fn ignores_the_byte_order_of_the_input() {
+    let mut input = Input::new(4).bytes(0, &[0x2A, 0x00, 0x00, 0x00]);
+    let value = decode_be::<Le<u32>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(value.get(), 42);
+}
+
+/// Returns the byte order other than that of the host.
+fn foreign() -> Endianness {
+    match Endianness::native() {
+        Endianness::Little => Endianness::Big,
+        Endianness::Big => Endianness::Little,
+    }
+}
+
+/// Returns `word` as bytes in the byte order `endianness`.
+fn word_bytes(word: usize, endianness: Endianness) -> [u8; WORD] {
+    match endianness {
+        Endianness::Little => word.to_le_bytes(),
+        Endianness::Big => word.to_be_bytes(),
+    }
+}
+
+#[test]
+fn swaps_only_the_words_of_pointers() {
+    for endianness in [foreign(), Endianness::native()] {
+        let mut input = Input::new(2 * WORD + 8)
+            .bytes(0, &word_bytes(2 * WORD, endianness))
+            .bytes(WORD, &word_bytes(2, endianness))
+            .u32(2 * WORD, 1)
+            .u32(2 * WORD + 4, 0x01020304);
+        let slice =
+            decode_header_endian::<&[u32]>(input.as_mut_bytes(), endianness);
+        assert_eq!(*slice.unwrap(), &[1, 0x01020304]);
+    }
+}
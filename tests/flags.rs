@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use ignominie::{ErrorKind, decode, decode_be, decode_le};
+
+#[derive(Debug)]
+#[repr(transparent)]
+struct Flags8(u8);
+
+#[derive(Debug)]
+#[repr(transparent)]
+struct Flags32(u32);
+
+flags_exhume! {
+    Flags8: u8 => 0b11,
+    Flags32: u32 => 0b1011,
+}
+
+#[test]
+fn accepts_known_flags() {
+    assert_eq!(decode::<Flags8>(&mut [0b11]).unwrap().0, 0b11);
+    let mut bits = 0b1001u32;
+    let bytes = bytes_of(&mut bits);
+    assert_eq!(decode::<Flags32>(bytes).unwrap().0, 0b1001);
+}
+
+#[test]
+fn rejects_unknown_flags() {
+    let error = decode::<Flags8>(&mut [0b100]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    let mut bits = 0b100u32;
+    let bytes = bytes_of(&mut bits);
+    let error = decode::<Flags32>(bytes).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn reads_flags_in_the_byte_order_of_the_input() {
+    let mut bits = 0b1001u32.to_be();
+    let bytes = bytes_of(&mut bits);
+    assert_eq!(decode_be::<Flags32>(bytes).unwrap().0, 0b1001);
+    let mut bits = 0b1001u32.to_le();
+    let bytes = bytes_of(&mut bits);
+    assert_eq!(decode_le::<Flags32>(bytes).unwrap().0, 0b1001);
+    let mut bits = 0b100u32.to_be();
+    let bytes = bytes_of(&mut bits);
+    let error = decode_be::<Flags32>(bytes).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+mod shadowed {
+    #[allow(dead_code)]
+    type Result = ();
+
+    #[repr(transparent)]
+    pub struct Flags(pub u8);
+
+    flags_exhume!(Flags: u8 => 0b1);
+}
+
+#[test]
+fn expands_where_result_is_shadowed() {
+    assert_eq!(decode::<shadowed::Flags>(&mut [0b1]).unwrap().0, 0b1);
+}
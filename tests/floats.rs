@@ -0,0 +1,67 @@
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use ignominie::{ErrorKind, StrictFloats, decode, decode_strict};
+
+fn decode_f32(mut bits: u32) -> Result<f32, ErrorKind> {
+    let value = decode::<f32>(bytes_of(&mut bits));
+    value.copied().map_err(|error| error.kind())
+}
+
+fn decode_f64(mut bits: u64, floats: StrictFloats) -> Result<f64, ErrorKind> {
+    let value = decode_strict::<f64>(bytes_of(&mut bits), floats);
+    value.copied().map_err(|error| error.kind())
+}
+
+#[test]
+fn rejects_signaling_nans() {
+    assert_eq!(decode_f32(0x7FA00000), Err(ErrorKind::Invalid));
+    assert_eq!(decode_f32(0xFF800001), Err(ErrorKind::Invalid));
+    let floats = StrictFloats::default();
+    assert_eq!(decode_f64(0x7FF4000000000000, floats), Err(ErrorKind::Invalid));
+    assert_eq!(decode_f64(0x7FF0000000000001, floats), Err(ErrorKind::Invalid));
+}
+
+#[test]
+fn accepts_quiet_nans_and_infinities() {
+    assert!(decode_f32(0x7FC00000).unwrap().is_nan());
+    assert!(decode_f32(0x7FC00001).unwrap().is_nan());
+    assert_eq!(decode_f32(0x7F800000), Ok(f32::INFINITY));
+    let floats = StrictFloats::default();
+    assert!(decode_f64(0x7FF8000000000001, floats).unwrap().is_nan());
+    assert_eq!(decode_f64(0xFFF0000000000000, floats), Ok(f64::NEG_INFINITY));
+}
+
+#[test]
+fn classifies_nans_by_their_quiet_bit() {
+    // Signaling with a payload, once taken as quiet.
+    assert_eq!(decode_f32(0x7F800001), Err(ErrorKind::Invalid));
+    // Quiet with a payload, once taken as signaling.
+    assert!(decode_f32(0x7FC00001).unwrap().is_nan());
+    let floats = StrictFloats::default();
+    assert_eq!(decode_f64(0x7FF0000000000001, floats), Err(ErrorKind::Invalid));
+    assert!(decode_f64(0x7FF8000000000001, floats).unwrap().is_nan());
+}
+
+#[test]
+fn rejects_nan_payloads_on_demand() {
+    let floats = StrictFloats::default().nan_payloads();
+    assert!(decode_f64(0x7FF8000000000000, floats).unwrap().is_nan());
+    assert_eq!(decode_f64(0x7FF8000000000001, floats), Err(ErrorKind::Invalid));
+}
+
+#[test]
+fn rejects_subnormals_on_demand() {
+    let floats = StrictFloats::default().subnormals();
+    let mut bits = 1u32;
+    assert_eq!(decode_f32(bits), Ok(f32::from_bits(1)));
+    let error = decode_strict::<f32>(bytes_of(&mut bits), floats).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    let mut bits = 0x80000000u32;
+    assert_eq!(decode_strict::<f32>(bytes_of(&mut bits), floats), Ok(&-0.0));
+    assert_eq!(decode_f64(1, StrictFloats::default()), Ok(f64::from_bits(1)));
+    assert_eq!(decode_f64(1, floats), Err(ErrorKind::Invalid));
+    assert_eq!(decode_f64(0, floats), Ok(0.0));
+}
@@ -0,0 +1,34 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, decode_after_header};
+
+#[test]
+fn decodes_roots_aligned_past_the_header() {
+    let mut input = Input::new(24)
+        .bytes(0, &[0xAA; 12])
+        .bytes(16, &42u64.to_ne_bytes());
+    let root = decode_after_header::<u64>(input.as_mut_bytes(), 12);
+    assert_eq!(root, Ok(&42));
+}
+
+#[test]
+fn counts_offsets_from_the_root() {
+    let root = 12usize.next_multiple_of(WORD);
+    let mut input = Input::new(root + WORD + 4)
+        .bytes(0, &[0xAA; 12])
+        .word(root, WORD)
+        .u32(root + WORD, 42);
+    let root = decode_after_header::<&u32>(input.as_mut_bytes(), 12);
+    assert_eq!(**root.unwrap(), 42);
+}
+
+#[test]
+fn rejects_roots_past_the_input() {
+    let mut input = Input::new(20);
+    let error = decode_after_header::<u64>(input.as_mut_bytes(), 12)
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
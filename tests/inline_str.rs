@@ -0,0 +1,37 @@
+extern crate ignominie;
+
+use ignominie::{ErrorKind, InlineStr, decode};
+
+/// Returns the bytes of an `InlineStr<7>` holding `bytes` and `len`.
+fn input(bytes: &[u8], len: u8) -> [u8; 8] {
+    let mut input = [0xFF; 8];
+    input[..bytes.len()].copy_from_slice(bytes);
+    input[7] = len;
+    input
+}
+
+#[test]
+fn decodes_inline_strings() {
+    let mut bytes = input(b"hello", 5);
+    assert_eq!(decode::<InlineStr<7>>(&mut bytes).unwrap().as_str(), "hello");
+    // Bytes past the length are ignored, even if not UTF-8.
+    let mut bytes = input(b"hello", 2);
+    assert_eq!(decode::<InlineStr<7>>(&mut bytes).unwrap().as_str(), "he");
+    let mut bytes = input(b"capable", 7);
+    let string = decode::<InlineStr<7>>(&mut bytes).unwrap();
+    assert_eq!(string.as_str(), "capable");
+}
+
+#[test]
+fn rejects_lengths_past_the_capacity() {
+    let mut bytes = input(b"capable", 8);
+    let error = decode::<InlineStr<7>>(&mut bytes).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn rejects_invalid_utf8_within_the_length() {
+    let mut bytes = input(b"he\xFFlo", 5);
+    let error = decode::<InlineStr<7>>(&mut bytes).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "test-utils")]
+
+#[macro_use]
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+
+#[repr(C)]
+struct Record<'input> {
+    name: Option<&'input [u8]>,
+    age: u32,
+}
+
+impl<'input> ignominie::Exhume<'input> for Record<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut ignominie::Heap<'input>,
+    ) -> Result<(), ignominie::Error> {
+        <Option<&[u8]>>::exhume(&mut (*this).name, heap)?;
+        u32::exhume(&mut (*this).age, heap)
+    }
+}
+
+#[repr(C)]
+struct Named<'input>(&'input str, u32);
+
+impl<'input> ignominie::Exhume<'input> for Named<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut ignominie::Heap<'input>,
+    ) -> Result<(), ignominie::Error> {
+        <&str>::exhume(&mut (*this).0, heap)?;
+        u32::exhume(&mut (*this).1, heap)
+    }
+}
+
+#[test]
+fn checks_types_valid_when_zeroed() {
+    assert_exhume_layout!(
+        Record<'_> { name: 0, age: 2 * WORD },
+        size = 3 * WORD,
+    );
+}
+
+#[test]
+#[should_panic(expected = "offset of Record<'_>.age")]
+fn catches_fields_at_other_offsets() {
+    assert_exhume_layout!(Record<'_> { name: 0, age: 0 }, size = 3 * WORD);
+}
+
+#[test]
+#[should_panic(expected = "size of Record<'_>")]
+fn catches_other_sizes() {
+    assert_exhume_layout!(
+        Record<'_> { name: 0, age: 2 * WORD },
+        size = 4 * WORD,
+    );
+}
+
+#[test]
+fn checks_types_decoded_from_a_given_input() {
+    let mut input = Input::new(3 * WORD).word(0, 3 * WORD);
+    assert_exhume_layout!(
+        Named<'_> { 0: 0, 1: 2 * WORD },
+        size = 3 * WORD,
+        input = input.as_mut_bytes(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "decoding Named<'_> failed")]
+fn catches_invalid_inputs() {
+    assert_exhume_layout!(Named<'_> { 0: 0, 1: 2 * WORD }, size = 3 * WORD);
+}
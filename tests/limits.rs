@@ -0,0 +1,93 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD, bytes_of};
+use ignominie::{
+    BoundedSlice,
+    DecodeStats,
+    ErrorKind,
+    Pair,
+    decode,
+    decode_metered,
+    decode_stats,
+    decode_with_budget,
+    decode_with_depth,
+};
+
+/// Returns an input holding a `&&&usize`, each pointer pointing to the
+/// word after it.
+fn input() -> [usize; 4] {
+    [WORD, 2 * WORD, 3 * WORD, 7]
+}
+
+#[test]
+fn decodes_without_limits() {
+    let mut words = input();
+    assert_eq!(****decode::<&&&usize>(bytes_of(&mut words)).unwrap(), 7);
+}
+
+#[test]
+fn bounds_the_depth() {
+    let mut words = input();
+    let root = decode_with_depth::<&&&usize>(bytes_of(&mut words), 3);
+    assert_eq!(****root.unwrap(), 7);
+    let mut words = input();
+    let error = decode_with_depth::<&&&usize>(bytes_of(&mut words), 2)
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::DepthExceeded);
+}
+
+#[test]
+fn bounds_the_budget() {
+    let mut words = input();
+    let root = decode_with_budget::<&&&usize>(bytes_of(&mut words), 8);
+    assert_eq!(****root.unwrap(), 7);
+    let mut words = input();
+    let error = decode_with_budget::<&&&usize>(bytes_of(&mut words), 7)
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::LimitExceeded);
+}
+
+#[test]
+fn counts_reservations() {
+    let mut words = input();
+    let (_, stats) = decode_stats::<&&&usize>(bytes_of(&mut words)).unwrap();
+    let expected = DecodeStats {
+        bytes_consumed: 4 * WORD,
+        reservation_count: 4,
+        max_offset: 4 * WORD,
+    };
+    assert_eq!(stats, expected);
+}
+
+#[test]
+fn meters_references_and_slices() {
+    let mut input = Input::new(3 * WORD + 12)
+        .word(0, 3 * WORD)
+        .word(WORD, 3 * WORD + 4)
+        .word(2 * WORD, 2)
+        .u32(3 * WORD, 1)
+        .u32(3 * WORD + 4, 2)
+        .u32(3 * WORD + 8, 3);
+    let (root, count) =
+        decode_metered::<Pair<&u32, &[u32]>>(input.as_mut_bytes()).unwrap();
+    assert_eq!((*root.0, root.1), (1, &[2, 3][..]));
+    assert_eq!(count, 3);
+    let mut value = 7usize;
+    assert_eq!(decode_metered::<usize>(bytes_of(&mut value)), Ok((&7, 1)));
+}
+
+#[test]
+fn caps_bounded_slices() {
+    let input = |len| {
+        Input::new(2 * WORD + 12).word(0, 2 * WORD).word(WORD, len)
+    };
+    let mut at_cap = input(3);
+    let slice = decode::<BoundedSlice<u32, 3>>(at_cap.as_mut_bytes());
+    assert_eq!(slice.unwrap().len(), 3);
+    let mut past_cap = input(4);
+    let error = decode::<BoundedSlice<u32, 3>>(past_cap.as_mut_bytes())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::LimitExceeded);
+}
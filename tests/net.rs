@@ -0,0 +1,33 @@
+//! These only need `core::net`, and run without the `std` feature, which
+//! is not a default one.
+
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use ignominie::{ErrorKind, decode};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn decodes_addresses() {
+    let v4 = Ipv4Addr::new(192, 0, 2, 1);
+    let mut copy = v4;
+    assert_eq!(decode::<Ipv4Addr>(bytes_of(&mut copy)), Ok(&v4));
+    let v6 = Ipv6Addr::LOCALHOST;
+    let mut copy = v6;
+    assert_eq!(decode::<Ipv6Addr>(bytes_of(&mut copy)), Ok(&v6));
+    for addr in [IpAddr::V4(v4), IpAddr::V6(v6)] {
+        let mut copy = addr;
+        assert_eq!(decode::<IpAddr>(bytes_of(&mut copy)), Ok(&addr));
+    }
+}
+
+#[test]
+fn rejects_unknown_ip_addr_tags() {
+    let mut addr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let bytes = bytes_of(&mut addr);
+    bytes[0] = 0xFF;
+    let error = decode::<IpAddr>(bytes).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,25 @@
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use ignominie::{ErrorKind, decode};
+use std::num::{Saturating, Wrapping};
+
+#[test]
+fn decodes_arithmetic_newtypes() {
+    let mut value = 42u32;
+    let wrapping = decode::<Wrapping<u32>>(bytes_of(&mut value));
+    assert_eq!(wrapping, Ok(&Wrapping(42)));
+    let mut value = 42u32;
+    let saturating = decode::<Saturating<u32>>(bytes_of(&mut value));
+    assert_eq!(saturating, Ok(&Saturating(42)));
+}
+
+#[test]
+fn validates_the_wrapped_value() {
+    let error = decode::<Wrapping<bool>>(&mut [2]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    let error = decode::<Saturating<bool>>(&mut [2]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,32 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, PointerKind, decode};
+
+#[test]
+fn reports_null_references() {
+    let mut input = Input::new(WORD);
+    let error = decode::<&u32>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NullPointer(PointerKind::Reference));
+}
+
+#[test]
+fn reports_null_slice_pointers() {
+    let mut input = Input::new(2 * WORD).word(WORD, 1);
+    let error = decode::<&[u32]>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NullPointer(PointerKind::Slice));
+}
+
+#[test]
+fn decodes_null_slice_pointers_as_none() {
+    let mut input = Input::new(2 * WORD).word(WORD, 1);
+    assert_eq!(decode::<Option<&[u32]>>(input.as_mut_bytes()), Ok(&None));
+    let mut input = Input::new(2 * WORD + 4)
+        .word(0, 2 * WORD)
+        .word(WORD, 1)
+        .u32(2 * WORD, 42);
+    let slice = decode::<Option<&[u32]>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(*slice, Some(&[42][..]));
+}
@@ -0,0 +1,29 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, Nullable, decode};
+
+#[test]
+fn is_absent_at_a_null_offset() {
+    let mut input = Input::new(WORD);
+    let root = decode::<Nullable<u32>>(input.as_mut_bytes()).unwrap();
+    assert!(root.is_null());
+    assert_eq!(root.as_ref(), None);
+}
+
+#[test]
+fn relocates_other_offsets() {
+    let mut input = Input::new(WORD + 4).word(0, WORD).u32(WORD, 7);
+    let root = decode::<Nullable<u32>>(input.as_mut_bytes()).unwrap();
+    assert!(!root.is_null());
+    assert_eq!(root.as_ref(), Some(&7));
+}
+
+#[test]
+fn rejects_an_offset_out_of_bounds() {
+    let mut input = Input::new(WORD + 4).word(0, WORD + 4);
+    let error = decode::<Nullable<u32>>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
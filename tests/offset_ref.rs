@@ -0,0 +1,54 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{ErrorKind, OffsetRef, decode};
+
+#[test]
+fn reads_the_pointee_from_a_copy() {
+    let input = || Input::new(16).word(0, 8).u32(8, 42);
+    let copy = input();
+    let mut input = input();
+    let offset = decode::<OffsetRef<u32>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(offset.offset(), 8);
+    assert_eq!(unsafe { *offset.get(copy.as_bytes()) }, 42);
+}
+
+#[test]
+fn rejects_pointees_past_the_input() {
+    let mut input = Input::new(16).word(0, 16);
+    let error = decode::<OffsetRef<u32>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "size_of")]
+fn catches_bases_too_short_for_the_pointee() {
+    let input = || Input::new(16).word(0, 8).u32(8, 42);
+    let copy = input();
+    let mut input = input();
+    let offset = decode::<OffsetRef<u32>>(input.as_mut_bytes()).unwrap();
+    unsafe {
+        offset.get(&copy.as_bytes()[..10]);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "align_of")]
+fn catches_misaligned_pointees() {
+    let copy = Input::new(16).word(0, 9);
+    let mut input = Input::new(16).word(0, 9);
+    let offset = decode::<OffsetRef<u8>>(input.as_mut_bytes()).unwrap();
+    // Both are a lone offset, which only the alignment checked differs by.
+    let offset = unsafe {
+        &*(offset as *const OffsetRef<u8> as *const OffsetRef<u32>)
+    };
+    unsafe {
+        offset.get(copy.as_bytes());
+    }
+}
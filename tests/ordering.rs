@@ -0,0 +1,34 @@
+extern crate ignominie;
+
+use ignominie::{ErrorKind, decode};
+use std::cmp::Ordering;
+use std::mem;
+use std::slice;
+
+#[test]
+fn decodes_every_option_of_ordering() {
+    let values = [
+        None,
+        Some(Ordering::Less),
+        Some(Ordering::Equal),
+        Some(Ordering::Greater),
+    ];
+    for &value in &values {
+        let mut byte = unsafe { mem::transmute::<Option<Ordering>, u8>(value) };
+        let bytes = slice::from_mut(&mut byte);
+        assert_eq!(decode::<Option<Ordering>>(bytes), Ok(&value));
+    }
+}
+
+#[test]
+fn rejects_other_bytes() {
+    let mut accepted = 0;
+    for byte in 0..=u8::MAX {
+        let mut byte = byte;
+        match decode::<Option<Ordering>>(slice::from_mut(&mut byte)) {
+            Ok(_) => accepted += 1,
+            Err(error) => assert_eq!(error.kind(), ErrorKind::Invalid),
+        }
+    }
+    assert_eq!(accepted, 4);
+}
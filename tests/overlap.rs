@@ -0,0 +1,75 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, Pair, decode};
+use std::mem;
+use std::ptr;
+
+/// Returns an input holding a pair of slices of two `u32`s, the second
+/// starting `second` bytes after the first.
+fn input(second: usize) -> Input {
+    Input::new(4 * WORD + 16)
+        .word(0, 4 * WORD)
+        .word(WORD, 2)
+        .word(2 * WORD, 4 * WORD + second)
+        .word(3 * WORD, 2)
+}
+
+#[test]
+fn accepts_disjoint_slices() {
+    let mut input = input(8);
+    assert!(decode::<Pair<&[u32], &[u32]>>(input.as_mut_bytes()).is_ok());
+}
+
+#[test]
+fn rejects_overlapping_slices() {
+    for second in [0, 4] {
+        let mut input = input(second);
+        let error = decode::<Pair<&[u32], &[u32]>>(input.as_mut_bytes())
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Overlap);
+    }
+}
+
+#[test]
+fn rejects_strings_inside_the_root() {
+    let mut input = Input::new(3 * WORD)
+        .word(0, 2 * WORD)
+        .word(WORD, 4)
+        .bytes(2 * WORD, b"root");
+    let error = decode::<Pair<&str, u64>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overlap);
+}
+
+/// Returns an input holding a `(&u32, &u32)` pointing to `first` and
+/// `second`, wherever the compiler laid the tuple out.
+fn tuple(first: usize, second: usize) -> Input {
+    Input::new(4 * WORD)
+        .word(mem::offset_of!((&u32, &u32), 0), first)
+        .word(mem::offset_of!((&u32, &u32), 1), second)
+        .u32(2 * WORD, 1)
+        .u32(3 * WORD, 2)
+}
+
+#[test]
+fn relocates_references_to_distinct_pointees() {
+    let mut input = tuple(2 * WORD, 3 * WORD);
+    let pair = decode::<(&u32, &u32)>(input.as_mut_bytes()).unwrap();
+    let (first, second) = *pair;
+    assert_eq!((*first, *second), (1, 2));
+    assert!(!ptr::eq(first, second));
+}
+
+#[test]
+fn rejects_references_sharing_a_pointee() {
+    let mut input = tuple(2 * WORD, 2 * WORD);
+    let error = decode::<(&u32, &u32)>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overlap);
+}
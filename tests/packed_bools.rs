@@ -0,0 +1,48 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, PackedBools, decode};
+
+/// Returns an input holding a `PackedBools` of `len` booleans in the two
+/// bytes `0b1000_0001` and `0b1000_0000`, i.e. with 0, 7 and 15 set.
+fn input(len: usize) -> Input {
+    Input::new(3 * WORD + 2)
+        .word(0, 3 * WORD)
+        .word(WORD, 2)
+        .word(2 * WORD, len)
+        .bytes(3 * WORD, &[0b1000_0001, 0b1000_0000])
+}
+
+#[test]
+fn gets_its_bits_up_to_its_last_one() {
+    let mut input = input(16);
+    let bools = decode::<PackedBools>(input.as_mut_bytes()).unwrap();
+    assert_eq!(bools.len(), 16);
+    let ones = (0..16).filter(|&i| bools.get(i)).collect::<Vec<_>>();
+    assert_eq!(ones, [0, 7, 15]);
+}
+
+#[test]
+fn may_leave_bits_of_its_last_byte_unused() {
+    let mut input = input(9);
+    let bools = decode::<PackedBools>(input.as_mut_bytes()).unwrap();
+    assert!(bools.get(7));
+    assert!(!bools.get(8));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn panics_past_its_len() {
+    let mut input = input(9);
+    let bools = decode::<PackedBools>(input.as_mut_bytes()).unwrap();
+    bools.get(9);
+}
+
+#[test]
+fn rejects_more_bits_than_its_bytes_hold() {
+    let mut input = input(17);
+    let error = decode::<PackedBools>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
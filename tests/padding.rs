@@ -0,0 +1,33 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{ErrorKind, decode_with_padding};
+
+fn decode_padded(slack: usize, align_to: usize) -> Result<u32, ErrorKind> {
+    let mut input = Input::new(4 + slack).u32(0, 42);
+    let value = decode_with_padding::<u32>(input.as_mut_bytes(), align_to);
+    value.copied().map_err(|error| error.kind())
+}
+
+#[test]
+fn accepts_padding_shorter_than_the_alignment() {
+    assert_eq!(decode_padded(0, 8), Ok(42));
+    assert_eq!(decode_padded(3, 8), Ok(42));
+    assert_eq!(decode_padded(7, 8), Ok(42));
+}
+
+#[test]
+fn rejects_padding_as_long_as_the_alignment() {
+    assert_eq!(decode_padded(8, 8), Err(ErrorKind::TrailingBytes));
+    assert_eq!(decode_padded(9, 8), Err(ErrorKind::TrailingBytes));
+}
+
+#[test]
+fn accepts_no_padding_when_aligning_to_zero_or_one() {
+    assert_eq!(decode_padded(0, 0), Ok(42));
+    assert_eq!(decode_padded(0, 1), Ok(42));
+    assert_eq!(decode_padded(1, 0), Err(ErrorKind::TrailingBytes));
+    assert_eq!(decode_padded(1, 1), Err(ErrorKind::TrailingBytes));
+}
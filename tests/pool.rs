@@ -0,0 +1,35 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, PoolStr, decode, decode_with_pool};
+
+const POOL: [&str; 3] = ["zero", "one", "two"];
+
+#[test]
+fn resolves_indices_to_the_pooled_strings() {
+    let pool = POOL;
+    let mut input = Input::new(3 * WORD)
+        .word(0, 2)
+        .word(WORD, 0)
+        .word(2 * WORD, 2);
+    let root = decode_with_pool::<[PoolStr; 3]>(input.as_mut_bytes(), &pool);
+    let strings = root.unwrap().iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    assert_eq!(strings, ["two", "zero", "two"]);
+}
+
+#[test]
+fn rejects_an_index_past_the_pool() {
+    let pool = POOL;
+    let mut input = Input::new(WORD).word(0, 3);
+    let result = decode_with_pool::<PoolStr>(input.as_mut_bytes(), &pool);
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn has_an_empty_pool_elsewhere() {
+    let mut input = Input::new(WORD);
+    let error = decode::<PoolStr>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,41 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, decode_prefixed};
+
+#[test]
+fn decodes_after_a_matching_prefix() {
+    let mut input = Input::new(16).u32(0, 16).bytes(8, &42u64.to_ne_bytes());
+    assert_eq!(decode_prefixed::<u64>(input.as_mut_bytes()), Ok(&42));
+    // The root of a u32 follows the prefix directly.
+    let mut input = Input::new(8).u32(0, 8).u32(4, 42);
+    assert_eq!(decode_prefixed::<u32>(input.as_mut_bytes()), Ok(&42));
+}
+
+#[test]
+fn counts_offsets_from_the_root() {
+    let mut input = Input::new(WORD + 12)
+        .u32(0, WORD as u32 + 12)
+        .word(WORD, WORD)
+        .u32(2 * WORD, 42);
+    let root = decode_prefixed::<&u32>(input.as_mut_bytes()).unwrap();
+    assert_eq!(**root, 42);
+}
+
+#[test]
+fn rejects_mismatched_prefixes() {
+    for len in [15, 17] {
+        let mut input = Input::new(16).u32(0, len);
+        let error = decode_prefixed::<u64>(input.as_mut_bytes()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Invalid);
+    }
+}
+
+#[test]
+fn rejects_inputs_too_short_for_a_prefix() {
+    let mut input = Input::new(3);
+    let error = decode_prefixed::<u8>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
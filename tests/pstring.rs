@@ -0,0 +1,44 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, PString, decode};
+use std::mem;
+
+/// Returns an input holding a `&PString` pointing to `string` right after
+/// it.
+fn input(len: u32, string: &[u8]) -> Input {
+    let start = 2 * WORD;
+    Input::new(start + 4 + string.len())
+        .word(0, start)
+        .bytes(start, &len.to_le_bytes())
+        .bytes(start + 4, string)
+}
+
+#[test]
+fn spans_its_bytes() {
+    let mut input = input(5, b"hello");
+    let string = decode::<&PString>(input.as_mut_bytes()).unwrap();
+    assert_eq!(string.len(), 5);
+    assert_eq!(string.as_str(), "hello");
+    assert_eq!(mem::size_of_val(*string), 9);
+}
+
+#[test]
+fn rejects_lengths_past_the_input() {
+    let mut input = input(6, b"hello");
+    let error = decode::<&PString>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn rejects_invalid_utf8() {
+    let mut input = input(2, b"\xC3\x28");
+    let error = decode::<&PString>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,48 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, PointerKind, RawSlice, decode};
+use std::mem;
+
+#[test]
+fn packs_none_into_the_null_pointer() {
+    assert_eq!(
+        mem::size_of::<Option<RawSlice<u8>>>(),
+        mem::size_of::<RawSlice<u8>>(),
+    );
+    assert_eq!(
+        mem::size_of::<Option<RawSlice<u64>>>(),
+        mem::size_of::<RawSlice<u64>>(),
+    );
+}
+
+#[test]
+fn decodes_null_pointers_as_none() {
+    let mut input = Input::new(2 * WORD).word(WORD, 3);
+    let slice = decode::<Option<RawSlice<u8>>>(input.as_mut_bytes()).unwrap();
+    assert!(slice.is_none());
+}
+
+#[test]
+fn relocates_the_data_pointer() {
+    let mut input = Input::new(2 * WORD + 4)
+        .word(0, 2 * WORD)
+        .word(WORD, 1)
+        .u32(2 * WORD, 42);
+    let slice = decode::<Option<RawSlice<u32>>>(input.as_mut_bytes())
+        .unwrap()
+        .as_ref()
+        .unwrap();
+    assert_eq!(slice.as_slice(), &[42]);
+}
+
+#[test]
+fn rejects_null_pointers() {
+    let mut input = Input::new(2 * WORD);
+    let error = decode::<RawSlice<u8>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NullPointer(PointerKind::Slice));
+}
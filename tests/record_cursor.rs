@@ -0,0 +1,32 @@
+extern crate ignominie;
+
+use ignominie::{ErrorKind, RecordCursor};
+
+#[test]
+fn stops_early_without_validating_later_records() {
+    let mut bytes = [1, 0, 7];
+    let mut records = RecordCursor::<bool>::new(&mut bytes, 3).unwrap();
+    assert_eq!(records.next(), Some(Ok(&true)));
+    assert_eq!(records.next(), Some(Ok(&false)));
+    assert_eq!(records.remaining(), 1);
+}
+
+#[test]
+fn ends_after_an_invalid_record() {
+    let mut bytes = [1, 7, 0];
+    let mut records = RecordCursor::<bool>::new(&mut bytes, 3).unwrap();
+    assert_eq!(records.next(), Some(Ok(&true)));
+    let error = records.next().unwrap().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(records.remaining(), 0);
+    assert_eq!(records.next(), None);
+}
+
+#[test]
+fn rejects_records_past_the_input() {
+    let mut bytes = [1, 0, 1];
+    let error = RecordCursor::<bool>::new(&mut bytes, 4)
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
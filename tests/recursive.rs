@@ -0,0 +1,72 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Error, ErrorKind, Exhume, Heap, decode};
+
+#[repr(C)]
+struct Expr<'input> {
+    value: u32,
+    block: Option<&'input Stmt<'input>>,
+}
+
+#[repr(C)]
+struct Stmt<'input> {
+    expr: &'input Expr<'input>,
+    next: Option<&'input Stmt<'input>>,
+}
+
+impl<'input> Exhume<'input> for Expr<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u32::exhume(&mut (*this).value, heap)?;
+        <Option<&Stmt>>::exhume(&mut (*this).block, heap)
+    }
+}
+
+impl<'input> Exhume<'input> for Stmt<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        <&Expr>::exhume(&mut (*this).expr, heap)?;
+        <Option<&Stmt>>::exhume(&mut (*this).next, heap)
+    }
+}
+
+/// Returns an input holding, in pre-order, a block expression whose two
+/// statements are the expressions 1 and 2, the second statement's
+/// expression being at `last`. Each value takes up two words.
+fn input(last: usize) -> Input {
+    let at = |i: usize| 2 * WORD * i;
+    Input::new(at(5))
+        .word(at(0) + WORD, at(1))
+        .word(at(1), at(2))
+        .word(at(1) + WORD, at(3))
+        .u32(at(2), 1)
+        .word(at(3), last)
+        .u32(at(4), 2)
+}
+
+#[test]
+fn decodes_mutually_recursive_trees() {
+    let mut input = input(8 * WORD);
+    let expr = decode::<Expr>(input.as_mut_bytes()).unwrap();
+    let first = expr.block.unwrap();
+    assert_eq!(first.expr.value, 1);
+    assert!(first.expr.block.is_none());
+    let second = first.next.unwrap();
+    assert_eq!(second.expr.value, 2);
+    assert!(second.next.is_none());
+}
+
+#[test]
+fn rejects_shared_values() {
+    // Both statements point to the expression 1.
+    let mut input = input(4 * WORD);
+    let error = decode::<Expr>(input.as_mut_bytes()).map(|_| ()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overlap);
+}
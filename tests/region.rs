@@ -0,0 +1,54 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Error, ErrorKind, Exhume, Heap, decode};
+
+/// A `&[bool]` relocated through `Heap::region`, whose closure carries on
+/// after errors as careless containers may.
+#[derive(Debug)]
+#[repr(C)]
+struct Bools<'input>(&'input [bool]);
+
+impl<'input> Exhume<'input> for Bools<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        let words = this as *mut [usize; 2];
+        let [offset, len] = *words;
+        let bools = heap.region::<bool, _, _>(offset, len, |mut region| {
+            while let Some(result) = region.exhume_next() {
+                if result.is_err() {
+                    break;
+                }
+            }
+            region.finish()
+        })?;
+        *this = Bools(bools);
+        Ok(())
+    }
+}
+
+/// Returns an input holding a `Bools` followed by its `bools`.
+fn input(bools: [u8; 3]) -> Input {
+    Input::new(3 * WORD)
+        .word(0, 2 * WORD)
+        .word(WORD, 3)
+        .bytes(2 * WORD, &bools)
+}
+
+#[test]
+fn relocates_elements() {
+    let mut input = input([1, 0, 0]);
+    let bools = decode::<Bools>(input.as_mut_bytes()).unwrap();
+    assert_eq!(bools.0, [true, false, false]);
+}
+
+#[test]
+fn finish_fails_after_an_error() {
+    let mut input = input([1, 7, 0]);
+    let error = decode::<Bools>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
@@ -0,0 +1,36 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{ErrorKind, RelPtr, RelSlice, decode};
+
+#[test]
+fn resolves_pointers_from_their_own_address() {
+    let mut input = Input::new(2 * WORD).word(0, WORD).u32(WORD, 42);
+    let ptr = decode::<RelPtr<u32>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(**ptr, 42);
+}
+
+#[test]
+fn rejects_pointers_past_the_input() {
+    let mut input = Input::new(2 * WORD).word(0, 2 * WORD);
+    let error = decode::<RelPtr<u32>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn resolves_slices_in_copies_of_the_input() {
+    let mut original = Input::new(3 * WORD)
+        .word(0, 2 * WORD)
+        .word(WORD, 2)
+        .u32(2 * WORD, 1)
+        .u32(2 * WORD + 4, 2);
+    let slice = decode::<RelSlice<u32>>(original.as_mut_bytes()).unwrap();
+    assert_eq!(&**slice, &[1, 2]);
+    let copy = Input::new(3 * WORD).bytes(0, original.as_bytes());
+    let slice = unsafe { &*(copy.as_bytes().as_ptr() as *const RelSlice<u32>) };
+    assert_eq!(&**slice, &[1, 2]);
+}
@@ -0,0 +1,25 @@
+extern crate ignominie;
+
+use ignominie::{Exhume, Pair, RelPtr};
+
+fn relocation_free<'input, T>() -> bool
+where
+    T: Exhume<'input>,
+{
+    T::IS_RELOCATION_FREE
+}
+
+#[test]
+fn holds_for_types_without_pointers() {
+    assert!(relocation_free::<[u32; 4]>());
+    assert!(relocation_free::<Pair<u8, [f64; 2]>>());
+    assert!(relocation_free::<RelPtr<u32>>());
+}
+
+#[test]
+fn fails_for_types_with_pointers() {
+    assert!(!relocation_free::<&str>());
+    assert!(!relocation_free::<&[u32]>());
+    assert!(!relocation_free::<Pair<u32, &str>>());
+    assert!(!relocation_free::<[&u32; 4]>());
+}
@@ -0,0 +1,75 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{Error, ErrorKind, Exhume, Heap, Schema};
+use ignominie::{decode_typed, schema_hash};
+
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+struct Celsius(u32);
+
+impl Schema for Celsius {
+    const SCHEMA_HASH: u64 = schema_hash("Celsius(u32)");
+}
+
+impl<'input> Exhume<'input> for Celsius {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u32::exhume(&mut (*this).0, heap)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+struct Meters(u32);
+
+impl Schema for Meters {
+    const SCHEMA_HASH: u64 = schema_hash("Meters(u32)");
+}
+
+impl<'input> Exhume<'input> for Meters {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u32::exhume(&mut (*this).0, heap)
+    }
+}
+
+/// Returns an input holding `value` after the schema hash of `T`.
+fn input<T: Schema>(value: u32) -> Input {
+    Input::new(12)
+        .bytes(0, &T::SCHEMA_HASH.to_ne_bytes())
+        .u32(8, value)
+}
+
+#[test]
+fn decodes_a_buffer_written_for_the_same_type() {
+    let mut input = input::<Celsius>(21);
+    let root = decode_typed::<Celsius>(input.as_mut_bytes());
+    assert_eq!(root, Ok(&Celsius(21)));
+}
+
+#[test]
+fn rejects_a_buffer_written_for_another_type() {
+    let mut input = input::<Celsius>(21);
+    let error = decode_typed::<Meters>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::SchemaMismatch);
+}
+
+#[test]
+fn rejects_a_buffer_too_short_for_the_hash() {
+    let mut input = Input::new(4);
+    let error = decode_typed::<Celsius>(input.as_mut_bytes()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn hashes_with_fnv_1a() {
+    assert_eq!(schema_hash(""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(schema_hash("a"), 0xaf63_dc4c_8601_ec8c);
+}
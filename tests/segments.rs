@@ -0,0 +1,45 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{WORD, bytes_of};
+use ignominie::{ErrorKind, Segments};
+
+/// Two segments of two words, each a reference to its own second word.
+fn input() -> [usize; 4] {
+    [WORD, 1, WORD, 2]
+}
+
+#[test]
+fn decodes_segments_side_by_side() {
+    let mut words = input();
+    let mut segments = Segments::new(bytes_of(&mut words));
+    let first = segments.decode::<&usize>(0..2 * WORD).unwrap();
+    let second = segments.decode::<&usize>(2 * WORD..4 * WORD).unwrap();
+    assert_eq!((**first, **second), (1, 2));
+}
+
+#[test]
+fn rejects_segments_out_of_order() {
+    let mut words = input();
+    let mut segments = Segments::new(bytes_of(&mut words));
+    segments.decode::<&usize>(2 * WORD..4 * WORD).unwrap();
+    let error = segments.decode::<&usize>(0..2 * WORD).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overlap);
+}
+
+#[test]
+fn rejects_segments_out_of_bounds() {
+    let mut words = input();
+    let mut segments = Segments::new(bytes_of(&mut words));
+    let error = segments.decode::<&usize>(2 * WORD..5 * WORD).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn offsets_are_relative_to_the_segment() {
+    let mut words = [0, 1, 3 * WORD, 2];
+    let mut segments = Segments::new(bytes_of(&mut words));
+    let error = segments.decode::<&usize>(2 * WORD..4 * WORD).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+extern crate ignominie;
+
+use ignominie::SharedDecoded;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+#[test]
+fn reads_the_root_from_several_threads() {
+    let bytes = [1u32, 2, 3, 4].iter().flat_map(|n| n.to_ne_bytes()).collect();
+    let root = SharedDecoded::<[u32; 4]>::new(bytes).unwrap();
+    let threads = (0..4)
+        .map(|i| {
+            let root = root.clone();
+            thread::spawn(move || root[i])
+        })
+        .collect::<Vec<_>>();
+    let values = threads
+        .into_iter()
+        .map(|thread| thread.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, [1, 2, 3, 4]);
+}
+
+#[test]
+fn writes_through_atomics_from_several_threads() {
+    let root = SharedDecoded::<AtomicU32>::new(vec![0; 4]).unwrap();
+    let threads = (0..8)
+        .map(|_| {
+            let root = root.clone();
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    root.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(root.load(Ordering::Relaxed), 8000);
+}
@@ -0,0 +1,63 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{bytes_of, bytes_of_ref};
+use ignominie::{Be, ErrorKind, Pair, decode_split_sections};
+
+#[test]
+fn resolves_references_into_data() {
+    let mut header = [0usize, 8];
+    let data = [7u64, 42];
+    let header = bytes_of(&mut header);
+    let value = decode_split_sections::<Pair<&u64, &u64>>(
+        header,
+        bytes_of_ref(&data),
+    );
+    let value = value.unwrap();
+    assert_eq!((*value.0, *value.1), (7, 42));
+}
+
+#[test]
+fn stores_null_pointers_as_the_maximum_offset() {
+    let mut header = [usize::MAX, 0];
+    let data = [7u64];
+    let header = bytes_of(&mut header);
+    let value = decode_split_sections::<Pair<Option<&u64>, Option<&u64>>>(
+        header,
+        bytes_of_ref(&data),
+    );
+    let value = value.unwrap();
+    assert_eq!((value.0, value.1), (None, Some(&7)));
+}
+
+#[test]
+fn rejects_null_references() {
+    let mut header = [usize::MAX];
+    let data = [7u64];
+    let header = bytes_of(&mut header);
+    let error = decode_split_sections::<&u64>(header, bytes_of_ref(&data))
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::NullPointer(_)));
+}
+
+#[test]
+fn rejects_references_past_data() {
+    let mut header = [16usize];
+    let data = [0u64, 42];
+    let header = bytes_of(&mut header);
+    let error = decode_split_sections::<&u64>(header, bytes_of_ref(&data))
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+fn rejects_types_writing_to_data() {
+    let mut header = [0usize];
+    let data = [42u64.to_be()];
+    let header = bytes_of(&mut header);
+    let error = decode_split_sections::<&Be<u64>>(header, bytes_of_ref(&data))
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(data, [42u64.to_be()]);
+}
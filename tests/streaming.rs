@@ -0,0 +1,41 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{ErrorKind, validate_slice_streaming};
+
+#[test]
+fn stops_once_the_callback_asks() {
+    // The third value is invalid, but is never reached.
+    let mut input = Input::new(12).bytes(8, &[1, 0, 2, 1]);
+    let mut seen = vec![];
+    let result = validate_slice_streaming::<bool, _>(
+        input.as_mut_bytes(),
+        8,
+        4,
+        |&value| {
+            seen.push(value);
+            false
+        },
+    );
+    assert_eq!(result, Ok(()));
+    assert_eq!(seen, [true]);
+}
+
+#[test]
+fn stops_at_the_first_invalid_value() {
+    let mut input = Input::new(12).bytes(8, &[1, 0, 2, 1]);
+    let mut seen = vec![];
+    let result = validate_slice_streaming::<bool, _>(
+        input.as_mut_bytes(),
+        8,
+        4,
+        |&value| {
+            seen.push(value);
+            true
+        },
+    );
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::Invalid);
+    assert_eq!(seen, [true, false]);
+}
@@ -0,0 +1,35 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{CTaggedOption, ErrorKind, decode};
+
+#[test]
+fn decodes_both_tags() {
+    let mut input = Input::new(8).u32(0, 1).bytes(4, &[1]);
+    let option = decode::<CTaggedOption<bool>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(option.get(), Some(&true));
+    // The payload is ignored when the tag is 0.
+    let mut input = Input::new(8).bytes(4, &[2]);
+    let option = decode::<CTaggedOption<bool>>(input.as_mut_bytes()).unwrap();
+    assert_eq!(option.get(), None);
+}
+
+#[test]
+fn exhumes_the_payload() {
+    let mut input = Input::new(8).u32(0, 1).bytes(4, &[2]);
+    let error = decode::<CTaggedOption<bool>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn rejects_other_tags() {
+    let mut input = Input::new(8).u32(0, 2).bytes(4, &[1]);
+    let error = decode::<CTaggedOption<bool>>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
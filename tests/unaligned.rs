@@ -0,0 +1,41 @@
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{Error, Exhume, Heap, decode, decode_be};
+use std::ptr;
+
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    value: u32,
+}
+
+impl<'input> Exhume<'input> for Packed {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u8::exhume(ptr::addr_of_mut!((*this).tag), heap)?;
+        u32::exhume(ptr::addr_of_mut!((*this).value), heap)
+    }
+}
+
+#[test]
+fn reads_unaligned_fields() {
+    // The input is 8-aligned, so the value lies at an address 2 past that.
+    let mut input = Input::new(6).bytes(1, &[7]).bytes(2, &42u32.to_ne_bytes());
+    let input = &mut input.as_mut_bytes()[1..];
+    let packed = decode::<Packed>(input).unwrap();
+    assert_eq!(packed.tag, 7);
+    assert_eq!({ packed.value }, 42);
+}
+
+#[test]
+fn swaps_unaligned_fields() {
+    let mut input = Input::new(6).bytes(2, &42u32.to_be_bytes());
+    let input = &mut input.as_mut_bytes()[1..];
+    let packed = decode_be::<Packed>(input).unwrap();
+    assert_eq!({ packed.value }, 42);
+}
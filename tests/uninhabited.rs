@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of;
+use std::convert::Infallible;
+use ignominie::{Error, ErrorKind, Exhume, Heap, decode};
+
+#[repr(C)]
+struct Reply {
+    code: u32,
+    never: Infallible,
+}
+
+impl<'input> Exhume<'input> for Reply {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u32::exhume(&mut (*this).code, heap)?;
+        Infallible::exhume(&mut (*this).never, heap)
+    }
+}
+
+mod shadowed {
+    #[allow(dead_code)]
+    type Result = ();
+
+    pub enum Never {}
+
+    uninhabited_exhume!(Never);
+}
+
+#[test]
+fn rejects_structs_with_an_infallible_field() {
+    for code in [0u32, 1, u32::MAX] {
+        let mut code = code;
+        let error = decode::<Reply>(bytes_of(&mut code)).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Invalid);
+    }
+}
+
+#[test]
+fn rejects_user_uninhabited_types() {
+    let error = decode::<shadowed::Never>(&mut []).err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
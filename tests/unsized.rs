@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate ignominie;
+
+mod common;
+
+use common::Input;
+use ignominie::{
+    Error,
+    ErrorKind,
+    Exhume,
+    ExhumeUnsized,
+    Heap,
+    decode,
+    decode_be,
+};
+use std::mem;
+use std::ptr;
+
+#[repr(C)]
+struct Header {
+    len: u32,
+    tag: u32,
+}
+
+impl<'input> Exhume<'input> for Header {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        u32::exhume(&mut (*this).len, heap)?;
+        u32::exhume(&mut (*this).tag, heap)
+    }
+}
+
+#[repr(C)]
+struct Entries {
+    header: Header,
+    tail: [u8],
+}
+
+unsafe impl<'input> ExhumeUnsized<'input> for Entries {
+    type Header = Header;
+    type Element = u8;
+
+    unsafe fn tail_len(header: *const Header, heap: &Heap) -> usize {
+        heap.read_native(&(*header).len) as usize
+    }
+
+    fn from_raw_parts(data: *mut u8, len: usize) -> *mut Self {
+        ptr::slice_from_raw_parts_mut(data, len) as *mut Self
+    }
+}
+
+mod shadowed {
+    #[allow(dead_code)]
+    type Result = ();
+
+    unsized_exhume!(super::Entries);
+}
+
+/// Returns an input holding a `&Entries` pointing to entries with a single
+/// element at offset 16, whose size is 12 once padded.
+fn input(len: usize) -> Input {
+    Input::new(len).word(0, 16).u32(16, 1).bytes(24, &[42])
+}
+
+#[test]
+fn reads_the_tail_length_from_the_header() {
+    let mut input = input(28);
+    let entries = decode::<&Entries>(input.as_mut_bytes()).unwrap();
+    assert_eq!(entries.header.len, 1);
+    assert_eq!(&entries.tail, &[42]);
+    assert_eq!(mem::size_of_val(*entries), 12);
+}
+
+#[test]
+fn reads_the_tail_length_in_the_byte_order_of_the_input() {
+    let mut input = Input::new(28)
+        .bytes(0, &16usize.to_be_bytes())
+        .bytes(16, &1u32.to_be_bytes())
+        .bytes(24, &[42]);
+    let entries = decode_be::<&Entries>(input.as_mut_bytes()).unwrap();
+    assert_eq!(entries.header.len, 1);
+    assert_eq!(&entries.tail, &[42]);
+}
+
+#[test]
+fn rejects_trailing_padding_past_the_input() {
+    let mut input = input(25);
+    let error = decode::<&Entries>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+}
@@ -0,0 +1,58 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Error, ErrorKind, Exhume, Heap, Validate, decode_validated};
+
+#[repr(C)]
+struct Entries<'input> {
+    sorted: bool,
+    entries: &'input [u32],
+}
+
+impl<'input> Exhume<'input> for Entries<'input> {
+    unsafe fn exhume(
+        this: *mut Self,
+        heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        bool::exhume(&mut (*this).sorted, heap)?;
+        <&[u32]>::exhume(&mut (*this).entries, heap)
+    }
+}
+
+impl<'input> Validate for Entries<'input> {
+    fn finalize(&self) -> Result<(), Error> {
+        if self.sorted && self.entries.windows(2).any(|w| w[0] > w[1]) {
+            return Err(Error::invalid());
+        }
+        Ok(())
+    }
+}
+
+fn input(sorted: bool, entries: [u32; 2]) -> Input {
+    Input::new(4 * WORD)
+        .bytes(0, &[sorted as u8])
+        .word(WORD, 3 * WORD)
+        .word(2 * WORD, 2)
+        .u32(3 * WORD, entries[0])
+        .u32(3 * WORD + 4, entries[1])
+}
+
+#[test]
+fn accepts_values_keeping_their_invariants() {
+    for &(sorted, entries) in &[(true, [1, 2]), (false, [2, 1])] {
+        let mut input = input(sorted, entries);
+        let value = decode_validated::<Entries>(input.as_mut_bytes()).unwrap();
+        assert_eq!(value.entries, &entries);
+    }
+}
+
+#[test]
+fn rejects_values_breaking_their_invariants() {
+    let mut input = input(true, [2, 1]);
+    let error = decode_validated::<Entries>(input.as_mut_bytes())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+}
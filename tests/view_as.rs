@@ -0,0 +1,21 @@
+extern crate ignominie;
+
+mod common;
+
+use common::bytes_of_ref;
+use ignominie::{Be, ErrorKind, view_as};
+
+#[test]
+fn views_integers() {
+    let words = [1u32, 2, 3];
+    assert_eq!(view_as::<u32>(bytes_of_ref(&words)).unwrap(), &[1, 2, 3]);
+}
+
+#[test]
+fn rejects_types_writing_to_their_bytes() {
+    let words = [42u32.to_be()];
+    let bytes = bytes_of_ref(&words);
+    let error = view_as::<Be<u32>>(bytes).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Invalid);
+    assert_eq!(words, [42u32.to_be()]);
+}
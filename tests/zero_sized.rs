@@ -0,0 +1,30 @@
+extern crate ignominie;
+
+mod common;
+
+use common::{Input, WORD};
+use ignominie::{Error, Exhume, Heap, decode};
+
+#[repr(C, align(8))]
+struct Ping;
+
+impl<'input> Exhume<'input> for Ping {
+    unsafe fn exhume(
+        _this: *mut Self,
+        _heap: &mut Heap<'input>,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn decodes_from_empty_input() {
+    assert_eq!(decode::<()>(&mut []), Ok(&()));
+    assert!(decode::<Ping>(&mut []).is_ok());
+}
+
+#[test]
+fn decodes_misaligned_pointees() {
+    let mut input = Input::new(16).word(0, WORD + 1);
+    assert!(decode::<&Ping>(input.as_mut_bytes()).is_ok());
+}